@@ -1,10 +1,10 @@
 //! `#[delegate]` macro expansion on types (structs or enums).
 
-use std::iter;
+use std::{iter, mem};
 
 use itertools::Itertools as _;
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, format_ident, quote};
 #[cfg(doc)]
 use syn::{Attribute, Generics, Index, Path, Type, WhereClause};
 use syn::{
@@ -17,47 +17,280 @@ use syn::{
 
 use crate::{
     MacroPath,
-    util::{GenericsExt as _, WhereClauseExt as _},
+    util::{GenericsExt as _, TypeExt as _, WhereClauseExt as _},
 };
 
 /// Arguments for `#[delegate]` macro expansion on types (structs or enums).
 struct Args {
     /// `derive` attribute argument, specifying derived traits.
     derive: Punctuated<DeriveTrait, token::Comma>,
+
+    /// `pin` attribute argument, enabling pin projection into delegates.
+    pin: Option<syn::Ident>,
+
+    /// `as_value` attribute argument, specifying the zero-sized type to
+    /// delegate to for structs without fields.
+    as_value: Option<syn::Path>,
+
+    /// `via`, `via_mut` and `via_owned` attribute arguments, specifying the
+    /// accessors to obtain the delegate of a struct from.
+    via: ViaArgs,
+
+    /// `methods` attribute argument, specifying inherent methods to be
+    /// delegated.
+    methods: Vec<Method>,
+}
+
+impl Args {
+    /// Indicates whether these [`Args`] require a delegate to be specified.
+    fn requires_delegate(&self) -> bool {
+        !self.derive.is_empty()
+            || self.pin.is_some()
+            || !self.methods.is_empty()
+    }
 }
 
 impl Parse for Args {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
-        let mut this = Self { derive: Punctuated::new() };
-
-        if input.is_empty() {
-            return Ok(this);
-        }
+        let mut this = Self {
+            derive: Punctuated::new(),
+            pin: None,
+            as_value: None,
+            via: ViaArgs::default(),
+            methods: Vec::new(),
+        };
 
-        input
-            .parse::<syn::Ident>()
-            .ok()
-            .and_then(|i| (i == "derive").then_some(()))
-            .ok_or_else(|| {
-                syn::Error::new(input.span(), "unexpected attribute argument")
+        while !input.is_empty() {
+            let span = input.span();
+            let arg = input.parse::<syn::Ident>().map_err(|_err| {
+                syn::Error::new(span, "unexpected attribute argument")
             })?;
-        let args;
-        _ = syn::parenthesized!(args in input);
 
-        this.derive = Punctuated::parse_terminated(&args)?;
+            if arg == "derive" {
+                let args;
+                _ = syn::parenthesized!(args in input);
+
+                this.derive.extend(
+                    Punctuated::<DeriveTrait, token::Comma>::parse_terminated(
+                        &args,
+                    )?,
+                );
+            } else if arg == "pin" {
+                this.pin = Some(arg);
+            } else if arg == "as_value" {
+                _ = input.parse::<token::Eq>()?;
+                this.as_value = Some(input.parse()?);
+            } else if arg == "methods" {
+                let methods;
+                _ = syn::braced!(methods in input);
+
+                while !methods.is_empty() {
+                    this.methods.push(methods.parse()?);
+                }
+            } else if !this.via.parse_arg(&arg, input)? {
+                return Err(syn::Error::new(
+                    span,
+                    "unexpected attribute argument",
+                ));
+            }
+
+            if input.parse::<Option<token::Comma>>()?.is_none() {
+                break;
+            }
+        }
 
         Ok(this)
     }
 }
 
+/// `via`, `via_mut` and `via_owned` attribute arguments, specifying the
+/// accessors to obtain the delegate from.
+#[derive(Debug, Default)]
+struct ViaArgs {
+    /// `via` attribute argument, specifying the accessor borrowing the
+    /// delegate, along with the [`Type`] of the delegate.
+    via: Option<(syn::Path, syn::Type)>,
+
+    /// `via_mut` attribute argument, specifying the accessor borrowing the
+    /// delegate mutably.
+    via_mut: Option<syn::Path>,
+
+    /// `via_owned` attribute argument, specifying the accessor moving the
+    /// delegate out.
+    via_owned: Option<syn::Path>,
+}
+
+impl ViaArgs {
+    /// Parses the value of the provided `arg` from the provided
+    /// [`ParseStream`], if it's one of these [`ViaArgs`].
+    ///
+    /// Returns `false` if the provided `arg` is not one of these [`ViaArgs`].
+    fn parse_arg(
+        &mut self,
+        arg: &syn::Ident,
+        input: ParseStream<'_>,
+    ) -> syn::Result<bool> {
+        if arg == "via" {
+            _ = input.parse::<token::Eq>()?;
+            let path = input.parse()?;
+            _ = input.parse::<token::RArrow>()?;
+            self.via = Some((path, input.parse()?));
+        } else if arg == "via_mut" {
+            _ = input.parse::<token::Eq>()?;
+            self.via_mut = Some(input.parse()?);
+        } else if arg == "via_owned" {
+            _ = input.parse::<token::Eq>()?;
+            self.via_owned = Some(input.parse()?);
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Returns the [`Span`] of the first of these [`ViaArgs`], if any is
+    /// specified.
+    fn span(&self) -> Option<Span> {
+        self.via
+            .as_ref()
+            .map(|(path, _)| path.span())
+            .or_else(|| self.via_mut.as_ref().map(syn::spanned::Spanned::span))
+            .or_else(|| {
+                self.via_owned.as_ref().map(syn::spanned::Spanned::span)
+            })
+    }
+
+    /// Converts these [`ViaArgs`] into the [`Via`] accessors called on the
+    /// provided `source` [`Type`], if any are specified.
+    fn into_via(self, source: syn::Type) -> syn::Result<Option<Via>> {
+        let Some((get, ty)) = self.via else {
+            return self.span().map_or(Ok(None), |span| {
+                Err(syn::Error::new(
+                    span,
+                    "`via_mut` and `via_owned` attribute arguments require \
+                     `via` one",
+                ))
+            });
+        };
+        Ok(Some(Via {
+            get,
+            get_mut: self.via_mut,
+            get_owned: self.via_owned,
+            ty,
+            source,
+        }))
+    }
+}
+
 /// Arguments for `#[delegate]` attribute on structs fields or enums variants.
 struct InnerArgs {
     /// `as` attribute argument, specifying the external type this field/variant
     /// is referencing to.
     r#as: Option<syn::Type>,
+
+    /// `default` attribute argument, marking the enum variant to delegate
+    /// associated functions without receiver to.
+    default: Option<syn::Ident>,
+
+    /// `with` attribute argument, specifying the path to the functions to be
+    /// called for the enum variant instead of delegating to it.
+    with: Option<syn::Path>,
+
+    /// `as_value` attribute argument, specifying the zero-sized type to
+    /// delegate to for the unit enum variant.
+    as_value: Option<syn::Path>,
+
+    /// `to` attribute argument, marking the struct field to delegate to.
+    to: Option<syn::Ident>,
+
+    /// `deref` attribute argument, marking the field/variant to delegate to
+    /// the value behind its smart pointer or reference.
+    deref: Option<syn::Ident>,
+
+    /// `lock` attribute argument, marking the field/variant to delegate to
+    /// the value guarded by its lock.
+    lock: Option<syn::Ident>,
+
+    /// `via`, `via_mut` and `via_owned` attribute arguments, specifying the
+    /// accessors to obtain the delegate of the enum variant from.
+    via: ViaArgs,
+
+    /// `derive` attribute argument, specifying traits to be derived for the
+    /// struct by delegating them to the field.
+    derive: Vec<DeriveTrait>,
 }
 
 impl InnerArgs {
+    /// Validates the combinations of these [`InnerArgs`].
+    fn validate(&self) -> syn::Result<()> {
+        if let (Some(default), Some(_)) = (&self.default, &self.r#as) {
+            return Err(syn::Error::new(
+                default.span(),
+                "`default` attribute argument cannot be combined with `as`, \
+                 as external types cannot be converted back",
+            ));
+        }
+        if let Some(with) = &self.with {
+            if self.r#as.is_some() || self.default.is_some() {
+                return Err(syn::Error::new(
+                    with.span(),
+                    "`with` attribute argument cannot be combined with `as` \
+                     or `default`, as the variant is not delegated to",
+                ));
+            }
+        }
+        if let Some(as_value) = &self.as_value {
+            if self.r#as.is_some() || self.with.is_some() {
+                return Err(syn::Error::new(
+                    as_value.span(),
+                    "`as_value` attribute argument cannot be combined with \
+                     `as` or `with`",
+                ));
+            }
+        }
+        if let Some(deref) = &self.deref {
+            if self.r#as.is_some()
+                || self.with.is_some()
+                || self.as_value.is_some()
+            {
+                return Err(syn::Error::new(
+                    deref.span(),
+                    "`deref` attribute argument cannot be combined with `as`, \
+                     `with` or `as_value`",
+                ));
+            }
+        }
+        if let Some(lock) = &self.lock {
+            if self.r#as.is_some()
+                || self.with.is_some()
+                || self.as_value.is_some()
+                || self.deref.is_some()
+            {
+                return Err(syn::Error::new(
+                    lock.span(),
+                    "`lock` attribute argument cannot be combined with `as`, \
+                     `with`, `as_value` or `deref`",
+                ));
+            }
+        }
+        if let Some(span) = self.via.span() {
+            if self.r#as.is_some()
+                || self.default.is_some()
+                || self.with.is_some()
+                || self.as_value.is_some()
+                || self.deref.is_some()
+                || self.lock.is_some()
+            {
+                return Err(syn::Error::new(
+                    span,
+                    "`via` attribute argument cannot be combined with `as`, \
+                     `default`, `with`, `as_value`, `deref` or `lock`",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Creates new [`InnerArgs`] from the provided [`Attribute`]s and removes
     /// the corresponding attributes from the provided [`Attribute`]s.
     fn from_attrs(
@@ -84,17 +317,65 @@ impl InnerArgs {
 
 impl Parse for InnerArgs {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
-        let mut this = Self { r#as: None };
+        let mut this = Self {
+            r#as: None,
+            default: None,
+            with: None,
+            as_value: None,
+            to: None,
+            deref: None,
+            lock: None,
+            via: ViaArgs::default(),
+            derive: Vec::new(),
+        };
+
+        while !input.is_empty() {
+            if input.parse::<Option<token::As>>()?.is_some() {
+                _ = input.parse::<token::Eq>()?;
+                this.r#as = Some(input.parse()?);
+            } else {
+                let span = input.span();
+                let arg = input.parse::<syn::Ident>().map_err(|_err| {
+                    syn::Error::new(span, "unexpected attribute argument")
+                })?;
+                if arg == "default" {
+                    this.default = Some(arg);
+                } else if arg == "with" {
+                    _ = input.parse::<token::Eq>()?;
+                    this.with = Some(input.parse()?);
+                } else if arg == "as_value" {
+                    _ = input.parse::<token::Eq>()?;
+                    this.as_value = Some(input.parse()?);
+                } else if arg == "to" {
+                    this.to = Some(arg);
+                } else if arg == "deref" {
+                    this.deref = Some(arg);
+                } else if arg == "lock" {
+                    this.lock = Some(arg);
+                } else if arg == "derive" {
+                    let args;
+                    _ = syn::parenthesized!(args in input);
+
+                    this.derive.extend(
+                        args.parse_terminated(
+                            DeriveTrait::parse,
+                            token::Comma,
+                        )?,
+                    );
+                } else if !this.via.parse_arg(&arg, input)? {
+                    return Err(syn::Error::new(
+                        span,
+                        "unexpected attribute argument",
+                    ));
+                }
+            }
 
-        if input.is_empty() {
-            return Ok(this);
+            if input.parse::<Option<token::Comma>>()?.is_none() {
+                break;
+            }
         }
 
-        _ = input.parse::<token::As>().map_err(|e| {
-            syn::Error::new(e.span(), "unexpected attribute argument")
-        })?;
-        _ = input.parse::<token::Eq>()?;
-        this.r#as = Some(input.parse()?);
+        this.validate()?;
 
         Ok(this)
     }
@@ -109,12 +390,25 @@ pub(crate) struct Definition {
     /// [`Generics`] of this [`Definition`].
     generics: syn::Generics,
 
-    /// Delegated enum [`Variant`]s or a single struct [`Field`].
-    delegated: DelegatedTypes,
+    /// Delegated enum [`Variant`]s or a single struct [`Field`], if any.
+    delegated: Option<DelegatedTypes>,
 
     /// Traits to derive.
     derived_traits: Vec<DeriveTrait>,
 
+    /// Inherent [`Method`]s to delegate.
+    methods: Vec<Method>,
+
+    /// [`Definition`]s of proxy types delegating the traits derived on the
+    /// struct fields.
+    proxies: Vec<Self>,
+
+    /// Type to derive the traits for, if this [`Definition`] is a proxy of it.
+    target: Option<syn::Ident>,
+
+    /// Indicator whether pin projection into the delegated types is enabled.
+    pin: bool,
+
     /// Item of this [`Definition`].
     item: Item,
 
@@ -126,7 +420,14 @@ impl ToTokens for Definition {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.item.to_tokens(tokens);
         self.impl_convert().to_tokens(tokens);
+        self.impl_convert_pin().to_tokens(tokens);
+        self.impl_convert_back().to_tokens(tokens);
+        self.impl_convert_default().to_tokens(tokens);
         self.derive_traits().to_tokens(tokens);
+        self.impl_methods().to_tokens(tokens);
+        for proxy in &self.proxies {
+            proxy.to_tokens(tokens);
+        }
     }
 }
 
@@ -137,20 +438,57 @@ impl Definition {
         args: TokenStream,
     ) -> syn::Result<Self> {
         let args = syn::parse2::<Args>(args)?;
+        if let Some(as_value) = &args.as_value {
+            return Err(syn::Error::new(
+                as_value.span(),
+                "`as_value` attribute argument is allowed only on structs \
+                 without fields, consider placing it on unit enum variants",
+            ));
+        }
+        if let Some(span) = args.via.span() {
+            return Err(syn::Error::new(
+                span,
+                "`via` attribute argument is allowed only on structs, consider \
+                 placing it on enum variants",
+            ));
+        }
 
-        Ok(Self {
+        let variants = item
+            .variants
+            .iter_mut()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Variant>, _>>()?;
+        if let Some(var) = variants.iter().filter(|var| var.default).nth(1) {
+            return Err(syn::Error::new(
+                var.ident.span(),
+                "only one enum variant may be marked with \
+                 `#[delegate(default)]`",
+            ));
+        }
+        if let (Some(pin), true) =
+            (&args.pin, variants.iter().any(|var| !var.access().is_direct()))
+        {
+            return Err(syn::Error::new(
+                pin.span(),
+                "pin projection is not supported for delegates marked with \
+                 `deref`, `lock` or `via` attribute arguments, as they're not \
+                 structurally pinned",
+            ));
+        }
+
+        Self {
             ident: item.ident.clone(),
             generics: item.generics.clone(),
-            delegated: DelegatedTypes::Variants(
-                item.variants
-                    .iter_mut()
-                    .map(TryInto::try_into)
-                    .collect::<Result<_, _>>()?,
-            ),
+            pin: args.pin.is_some(),
+            delegated: Some(DelegatedTypes::Variants(variants)),
             derived_traits: args.derive.into_iter().collect(),
+            methods: args.methods,
+            proxies: Vec::new(),
+            target: None,
             item: Item::Enum(item),
             macro_path: MacroPath::default(),
-        })
+        }
+        .check_locked_methods()
     }
 
     /// Parses a [`Definition`] from the provided [`syn::ItemStruct`].
@@ -160,49 +498,237 @@ impl Definition {
     ) -> syn::Result<Self> {
         let args = syn::parse2::<Args>(args)?;
 
-        Ok(Self {
+        if let Some(pin) = &args.pin {
+            let is_packed = item.attrs.iter().any(|attr| {
+                type Metas = Punctuated<syn::Meta, token::Comma>;
+
+                attr.path().is_ident("repr")
+                    && attr.parse_args_with(Metas::parse_terminated).is_ok_and(
+                        |reprs| {
+                            reprs.iter().any(|r| r.path().is_ident("packed"))
+                        },
+                    )
+            });
+            if is_packed {
+                return Err(syn::Error::new(
+                    pin.span(),
+                    "pin projection is not supported for `#[repr(packed)]` \
+                     structs",
+                ));
+            }
+        }
+
+        let requires_delegate = args.requires_delegate();
+        let via = args.via.into_via(parse_quote! { Self })?;
+        let (delegated, derived) = match (args.as_value, via, &mut item.fields)
+        {
+            (Some(path), Some(_), _) => {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "`as_value` attribute argument cannot be combined with \
+                     `via`",
+                ));
+            }
+            (None, Some(via), fields) => {
+                if let Some(attr) = fields
+                    .iter()
+                    .flat_map(|f| &f.attrs)
+                    .find(|attr| attr.path().is_ident("delegate"))
+                {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "`#[delegate(..)]` attribute is not allowed on fields \
+                         of structs delegating via accessors",
+                    ));
+                }
+                (Some(Field::Via(Box::new(via))), Vec::new())
+            }
+            (Some(path), None, fields) if fields.is_empty() => {
+                let ty = Box::new(syn::Type::Path(syn::TypePath {
+                    qself: None,
+                    path: path.clone(),
+                }));
+                (Some(Field::Value { path, ty }), Vec::new())
+            }
+            (Some(path), None, _) => {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "`as_value` attribute argument is allowed only on structs \
+                     without fields",
+                ));
+            }
+            (None, None, fields) => {
+                let Fields { delegated, derived } = fields.try_into()?;
+                (delegated, derived)
+            }
+        };
+        if delegated.is_none() && requires_delegate {
+            return Err(syn::Error::new(
+                item.fields.span(),
+                "struct with multiple fields must have exactly one field \
+                 marked with `#[delegate(to)]`",
+            ));
+        }
+        if let (Some(pin), true) = (
+            &args.pin,
+            delegated.as_ref().is_some_and(|f| !f.access().is_direct()),
+        ) {
+            return Err(syn::Error::new(
+                pin.span(),
+                "pin projection is not supported for delegates marked with \
+                 `deref`, `lock` or `via` attribute arguments, as they're not \
+                 structurally pinned",
+            ));
+        }
+
+        let proxies = derived
+            .into_iter()
+            .enumerate()
+            .map(|(n, (field, traits))| Self::proxy(&item, n, field, traits))
+            .collect();
+
+        Self {
             ident: item.ident.clone(),
             generics: item.generics.clone(),
-            delegated: DelegatedTypes::Field(Box::new(
-                (&mut item.fields).try_into()?,
-            )),
+            pin: args.pin.is_some(),
+            delegated: delegated
+                .map(|field| DelegatedTypes::Field(Box::new(field))),
             derived_traits: args.derive.into_iter().collect(),
+            methods: args.methods,
+            proxies,
+            target: None,
             item: Item::Struct(item),
             macro_path: MacroPath::default(),
-        })
+        }
+        .check_locked_methods()
+    }
+
+    /// Checks that the inherent [`Method`]s with `&self` receiver return no
+    /// borrows, if some of the delegates are marked with the `lock` attribute
+    /// argument, as they're borrowed from temporary guards.
+    ///
+    /// Returns this [`Definition`] back, if the check passes.
+    fn check_locked_methods(self) -> syn::Result<Self> {
+        let is_locked = self.delegated.as_ref().is_some_and(|delegated| {
+            delegated
+                .delegates()
+                .any(|(_, access)| matches!(access, Access::Lock(_)))
+        });
+        if !is_locked {
+            return Ok(self);
+        }
+
+        for m in &self.methods {
+            if m.ref_tok.is_none() || m.mut_tok.is_some() {
+                continue;
+            }
+            let sig = &m.item.sig;
+            let borrowing = match &sig.output {
+                _ if sig.asyncness.is_some() => {
+                    Some(sig.asyncness.to_token_stream())
+                }
+                syn::ReturnType::Type(_, ty)
+                    if matches!(**ty, syn::Type::ImplTrait(_))
+                        || ty.contains_lifetimes() =>
+                {
+                    Some(ty.to_token_stream())
+                }
+                syn::ReturnType::Type(..) | syn::ReturnType::Default => None,
+            };
+            if let Some(tokens) = borrowing {
+                return Err(syn::Error::new_spanned(
+                    tokens,
+                    format!(
+                        "`{}` method cannot return borrows (or \
+                         `impl Trait`, or be `async`), as delegates marked \
+                         with `lock` attribute argument are borrowed from \
+                         temporary guards for `&self` methods",
+                        sig.ident,
+                    ),
+                ));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Creates a [`Definition`] of the `n`th proxy type of the provided struct,
+    /// delegating the provided `traits` of the struct to its `field`.
+    ///
+    /// The proxy type wraps the struct transparently, so the struct may be
+    /// transmuted into it, implementing the `Convert` trait for the `field`
+    /// only.
+    fn proxy(
+        item: &syn::ItemStruct,
+        n: usize,
+        field: Field,
+        traits: Vec<DeriveTrait>,
+    ) -> Self {
+        let ident = format_ident!("__{}Delegate{n}", item.ident);
+        let target = &item.ident;
+        let generics = &item.generics;
+        let (_, ty_gens, where_clause) = generics.split_for_impl();
+
+        let proxy = parse_quote! {
+            #[doc(hidden)]
+            #[allow(dead_code, reason = "macro expansion")]
+            #[repr(transparent)]
+            struct #ident #generics (#target #ty_gens) #where_clause;
+        };
+
+        Self {
+            ident,
+            generics: generics.clone(),
+            pin: false,
+            delegated: Some(DelegatedTypes::Field(Box::new(Field::Proxied(
+                Box::new(field),
+            )))),
+            derived_traits: traits,
+            methods: Vec::new(),
+            proxies: Vec::new(),
+            target: Some(target.clone()),
+            item: Item::Struct(proxy),
+            macro_path: MacroPath::default(),
+        }
     }
 
     /// Implements the `Convert` trait for the delegated type.
     fn impl_convert(&self) -> TokenStream {
+        let Some(delegated) = &self.delegated else {
+            return TokenStream::new();
+        };
         let macro_path = &self.macro_path;
         let ident = &self.ident;
 
         let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
 
         let lifetime = parse_quote! { '__delegate };
-        let either_owned =
-            self.generate_either(self.delegated.types(), None, false);
-        let either_ref = self.generate_either(
-            self.delegated.types(),
-            Some(&lifetime),
-            false,
-        );
-        let either_ref_mut =
-            self.generate_either(self.delegated.types(), Some(&lifetime), true);
+        let [owned, refs, refs_mut] =
+            [(None, false), (Some(&lifetime), false), (Some(&lifetime), true)]
+                .map(|(lt, is_mut)| {
+                    delegated.converted_types(lt, is_mut, macro_path)
+                });
+        let either_owned = self.generate_either(&owned, None, false);
+        let either_ref = self.generate_either(&refs, None, false);
+        let either_ref_mut = self.generate_either(&refs_mut, None, false);
 
         let mut either_where_clause: syn::WhereClause = parse_quote! { where };
-        either_where_clause.predicates.extend(self.delegated.types().map(
-            |ty| -> syn::WherePredicate {
-                parse_quote! { #ty: #lifetime }
-            },
-        ));
+        either_where_clause.predicates.extend(
+            delegated.types().chain(delegated.holders()).map(
+                |ty| -> syn::WherePredicate {
+                    parse_quote! { #ty: #lifetime }
+                },
+            ),
+        );
 
-        let (convert_owned, convert_ref, convert_ref_mut) = match &self
-            .delegated
-        {
+        let (convert_owned, convert_ref, convert_ref_mut) = match &delegated {
             DelegatedTypes::Variants(variants) => {
-                let arms = self.generate_match(variants);
-                (arms.clone(), arms.clone(), arms)
+                let l = token::And::default();
+                let m = token::Mut::default();
+                (
+                    self.generate_match(variants, None, None),
+                    self.generate_match(variants, Some(l), None),
+                    self.generate_match(variants, Some(l), Some(m)),
+                )
             }
             DelegatedTypes::Field(field) => {
                 let l = token::And::default();
@@ -245,12 +771,229 @@ impl Definition {
         }
     }
 
+    /// Implements the `ConvertBack` trait for the delegated type, unless some
+    /// of its delegates are external types (marked with the `as` attribute
+    /// argument), or the struct (or some of its enum variants) has other fields
+    /// besides the delegated one, or obtains it via accessors, as they cannot
+    /// be converted back.
+    fn impl_convert_back(&self) -> TokenStream {
+        let Some(delegated) = &self.delegated else {
+            return TokenStream::new();
+        };
+        let is_irreversible = match delegated {
+            DelegatedTypes::Variants(variants) => variants.iter().any(|var| {
+                var.wrapper_ty.is_some() || var.has_others || var.via.is_some()
+            }),
+            DelegatedTypes::Field(field) => {
+                field.wrapper_ty().is_some() || field.has_others()
+            }
+        };
+        if is_irreversible {
+            return TokenStream::new();
+        }
+
+        let macro_path = &self.macro_path;
+        let ident = &self.ident;
+
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        let either = |i: usize, expr: TokenStream| {
+            (0..i).fold(quote! { #macro_path::Either::Left(#expr) }, |e, _| {
+                quote! { #macro_path::Either::Right(#e) }
+            })
+        };
+        let ctors = match delegated {
+            DelegatedTypes::Variants(variants) => variants
+                .iter()
+                .filter(|var| var.with.is_none())
+                .map(|var| var.pattern(&from_owned(var.access(), macro_path)))
+                .collect::<Vec<_>>(),
+            DelegatedTypes::Field(field) => {
+                vec![field.constructor(&from_owned(field.access(), macro_path))]
+            }
+        };
+        let arms = ctors.iter().enumerate().map(|(i, ctor)| {
+            let pat = either(i, quote! { __delegate });
+            quote! { #pat => #ctor, }
+        });
+        let void_pat = (0..ctors.len()).fold(quote! { __void }, |e, _| {
+            quote! { #macro_path::Either::Right(#e) }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_gens #macro_path::ConvertBack for #ident #ty_gens
+                 #where_clause
+            {
+                fn convert_back(
+                    owned: <Self as #macro_path::Convert>::Owned,
+                ) -> Self {
+                    match owned {
+                        #( #arms )*
+                        #void_pat => match __void {},
+                    }
+                }
+            }
+        }
+    }
+
+    /// Implements the `ConvertDefault` trait for the delegated type, if it's an
+    /// enum having a variant marked with `#[delegate(default)]`, or a struct
+    /// having a non-external field.
+    fn impl_convert_default(&self) -> TokenStream {
+        let Some((ty, ctor)) =
+            self.delegated.as_ref().and_then(DelegatedTypes::default)
+        else {
+            return TokenStream::new();
+        };
+
+        let macro_path = &self.macro_path;
+        let ident = &self.ident;
+
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_gens #macro_path::ConvertDefault for #ident #ty_gens
+                 #where_clause
+            {
+                type Default = #ty;
+
+                fn convert_default(__delegate: #ty) -> Self {
+                    #ctor
+                }
+            }
+        }
+    }
+
+    /// Implements the `ConvertPin` trait for the delegated type, if the `pin`
+    /// attribute argument is specified.
+    ///
+    /// Delegates are projected structurally, so the type is additionally
+    /// asserted to not implement `Drop` (which could move them out), and to be
+    /// `Unpin` only if all the delegates are.
+    fn impl_convert_pin(&self) -> TokenStream {
+        let Some(delegated) = &self.delegated else {
+            return TokenStream::new();
+        };
+        if !self.pin {
+            return TokenStream::new();
+        }
+
+        let macro_path = &self.macro_path;
+        let ident = &self.ident;
+
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        let lifetime: syn::Lifetime = parse_quote! { '__delegate };
+        let pinned = delegated
+            .types()
+            .map(|ty| -> syn::Type {
+                parse_quote! { ::core::pin::Pin<&#lifetime mut #ty> }
+            })
+            .collect::<Vec<_>>();
+        let either_pinned = self.generate_either(&pinned, None, false);
+
+        let mut either_where_clause: syn::WhereClause = parse_quote! { where };
+        either_where_clause.predicates.extend(delegated.types().map(
+            |ty| -> syn::WherePredicate {
+                parse_quote! { #ty: #lifetime }
+            },
+        ));
+
+        let either = |i: usize, expr: TokenStream| {
+            (0..i).fold(quote! { #macro_path::Either::Left(#expr) }, |e, _| {
+                quote! { #macro_path::Either::Right(#e) }
+            })
+        };
+        let arms = (0..pinned.len()).map(|i| {
+            let pat = either(i, quote! { __delegate });
+            let expr = either(
+                i,
+                quote! { ::core::pin::Pin::new_unchecked(__delegate) },
+            );
+            quote! { #pat => #expr, }
+        });
+        let void_pat = (0..pinned.len()).fold(quote! { __void }, |e, _| {
+            quote! { #macro_path::Either::Right(#e) }
+        });
+
+        let unpin_generics = {
+            let mut gens = self.generics.clone();
+            gens.params.insert(0, parse_quote! { #lifetime });
+            let types = delegated.types();
+            gens.make_where_clause().predicates.push(parse_quote! {
+                (
+                    #( #types, )*
+                    ::core::marker::PhantomData<&#lifetime ()>,
+                ): ::core::marker::Unpin
+            });
+            gens
+        };
+        let (unpin_impl_gens, _, unpin_where_clause) =
+            unpin_generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_gens #macro_path::ConvertPin for #ident #ty_gens
+                 #where_clause
+            {
+                type PinMut<#lifetime> = #either_pinned #either_where_clause;
+
+                fn convert_pin_mut(
+                    self: ::core::pin::Pin<&mut Self>,
+                ) -> <Self as #macro_path::ConvertPin>::PinMut<'_> {
+                    // SAFETY: Delegates are structurally pinned, as this type
+                    //         neither implements `Drop`, nor is `Unpin`, unless
+                    //         all the delegates are (asserted below).
+                    #[allow(unsafe_code, reason = "macro expansion")]
+                    unsafe {
+                        match <Self as #macro_path::Convert>::convert_ref_mut(
+                            ::core::pin::Pin::get_unchecked_mut(self),
+                        ) {
+                            #( #arms )*
+                            #void_pat => match __void {},
+                        }
+                    }
+                }
+            }
+
+            // Implementing `Unpin` manually conflicts with this impl, so the
+            // type cannot be `Unpin` while some of its delegates are not.
+            #[automatically_derived]
+            impl #unpin_impl_gens ::core::marker::Unpin for #ident #ty_gens
+                 #unpin_where_clause
+            {}
+
+            // Implementing `Drop` conflicts with the blanket impl below.
+            const _: () = {
+                trait MustNotImplDrop {}
+
+                #[allow(drop_bounds, reason = "macro expansion")]
+                impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+
+                impl #impl_gens MustNotImplDrop for #ident #ty_gens
+                     #where_clause
+                {}
+            };
+        }
+    }
+
     /// Derives traits specified in the `derive(..)` attribute argument for this
     /// type.
     fn derive_traits(&self) -> TokenStream {
+        let Some(delegated) = &self.delegated else {
+            return TokenStream::new();
+        };
         let macro_path = &self.macro_path;
-        let ident = &self.ident;
         let (_, ty_gens, _) = self.generics.split_for_impl();
+        let (ident, via) = self.target.as_ref().map_or_else(
+            || (&self.ident, None),
+            |target| {
+                let proxy = &self.ident;
+                (target, Some(quote! { , via(#proxy #ty_gens) }))
+            },
+        );
 
         self.derived_traits
             .iter()
@@ -268,17 +1011,142 @@ impl Definition {
                     ToTokens::to_token_stream,
                 );
 
+                let types = delegated.types();
+                let default = delegated
+                    .default()
+                    .map(|(ty, _)| quote! { , default(#ty) });
+                let with = delegated.with_arms();
+                let skip = &p.skip;
+                let skip = (!skip.is_empty())
+                    .then(|| quote! { , skip( #( #skip ),* ) });
+                let pointers = delegated.pointers().collect::<Vec<_>>();
+                let deref = (!pointers.is_empty())
+                    .then(|| quote! { , deref( #( #pointers ),* ) });
+
                 quote! {
                     #macro_rules_path!(
                         impl #impl_gens #trait_path as #wrapper
                         for #ident #ty_gens
-                        #where_clause
+                        #where_clause;
+                        delegated( #( #types ),* )
+                        #via
+                        #default
+                        #with
+                        #skip
+                        #deref
                     );
                 }
             })
             .collect()
     }
 
+    /// Implements the inherent [`Method`]s specified in the `methods { .. }`
+    /// attribute argument for this type.
+    fn impl_methods(&self) -> TokenStream {
+        let Some(delegated) = &self.delegated else {
+            return TokenStream::new();
+        };
+        if self.methods.is_empty() {
+            return TokenStream::new();
+        }
+        let ident = &self.ident;
+
+        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+
+        let methods =
+            self.methods.iter().map(|m| self.delegate_method(delegated, m));
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_gens #ident #ty_gens #where_clause {
+                #( #methods )*
+            }
+        }
+    }
+
+    /// Implements the provided inherent [`Method`] by calling the same-named
+    /// method of the provided delegates.
+    ///
+    /// Variants delegated to `with` functions are matched before converting,
+    /// calling the same-named functions under their paths instead.
+    fn delegate_method(
+        &self,
+        delegated: &DelegatedTypes,
+        method: &Method,
+    ) -> TokenStream {
+        let macro_path = &self.macro_path;
+        let Method { item, args, ref_tok, mut_tok } = method;
+        let syn::ForeignItemFn { attrs, vis, sig, .. } = item;
+        let name = &sig.ident;
+        let await_tok = sig.asyncness.map(|_| quote! { .await });
+
+        let convert = match (ref_tok, mut_tok) {
+            (None, _) => quote! { convert_owned },
+            (Some(_), None) => quote! { convert_ref },
+            (Some(_), Some(_)) => quote! { convert_ref_mut },
+        };
+        let arms = delegated.delegates().enumerate().map(|(i, (_, access))| {
+            let pat = (0..i).fold(
+                quote! { #macro_path::Either::Left(__delegate) },
+                |e, _| quote! { #macro_path::Either::Right(#e) },
+            );
+            // Locked delegates are locked for the call only.
+            let receiver = match access {
+                Access::Lock(lock)
+                    if mut_tok.is_none() && ref_tok.is_some() =>
+                {
+                    quote! {
+                        &*<#lock as #macro_path::Lock>::lock(__delegate.0)
+                    }
+                }
+                Access::Direct
+                | Access::Deref(_)
+                | Access::Lock(_)
+                | Access::Via(_) => quote! { __delegate },
+            };
+            quote! {
+                #pat => (#receiver).#name(#( #args ),*) #await_tok,
+            }
+        });
+        let void_pat = (0..delegated.delegates().count()).fold(
+            quote! { __void },
+            |e, _| {
+                quote! { #macro_path::Either::Right(#e) }
+            },
+        );
+
+        let with_arms = match delegated {
+            DelegatedTypes::Variants(variants) => variants
+                .iter()
+                .filter_map(|var| {
+                    let path = var.with.as_ref()?;
+                    let pat = var.pattern(&quote! { __delegate });
+                    Some(quote! {
+                        #pat => #path::#name(
+                            __delegate, #( #args ),*
+                        ) #await_tok,
+                    })
+                })
+                .collect(),
+            DelegatedTypes::Field(_) => Vec::new(),
+        };
+
+        quote! {
+            #( #attrs )*
+            #vis #sig {
+                match self {
+                    #( #with_arms )*
+                    __self => match <Self as #macro_path::Convert>::#convert(
+                        __self,
+                    ) {
+                        #( #arms )*
+                        #void_pat => match __void {},
+                    },
+                }
+            }
+        }
+    }
+
     /// Generates an `Either` type like
     /// `Either<Ty1, <... Either<TyN, Void>> ...>` with optionally added maybe
     /// mutable reference before each `TyN`.
@@ -342,7 +1210,12 @@ impl Definition {
     ///     Self::VariantN(v) => Either::Right(v),
     /// }
     /// ```
-    fn generate_match(&self, variants: impl AsRef<[Variant]>) -> TokenStream {
+    fn generate_match(
+        &self,
+        variants: impl AsRef<[Variant]>,
+        ref_tok: Option<token::And>,
+        mut_tok: Option<token::Mut>,
+    ) -> TokenStream {
         fn sequence(
             tokens: &mut TokenStream,
             count: usize,
@@ -368,26 +1241,47 @@ impl Definition {
         token::Match::default().to_tokens(&mut tokens);
         token::SelfValue::default().to_tokens(&mut tokens);
         token::Brace::default().surround(&mut tokens, |toks| {
-            for (i, variant) in variants.as_ref().iter().enumerate() {
-                token::SelfType::default().to_tokens(toks);
-                token::PathSep::default().to_tokens(toks);
-                variant.ident.to_tokens(toks);
+            let mut i = 0;
+            for variant in variants.as_ref() {
+                // Variants delegated to `with` functions are matched before
+                // converting, so they're never converted.
+                if variant.with.is_some() {
+                    let pat = variant.pattern(&quote! { _ });
+                    quote! {
+                        #pat => {
+                            ::core::unreachable!(
+                                "`with` variants are never converted",
+                            )
+                        }
+                    }
+                    .to_tokens(toks);
+                    continue;
+                }
 
-                let val = if let Some(ident) = &variant.field_ident {
+                // Unit variants are delegated to the synthesized `as_value`.
+                if let Some(path) = &variant.value {
+                    let pat = variant.pattern(&TokenStream::new());
+                    let expr = zst_value(path, ref_tok, mut_tok);
+                    quote! { #pat => }.to_tokens(toks);
                     token::Brace::default().surround(toks, |t| {
-                        ident.to_tokens(t);
-                    });
-                    ident.clone()
-                } else {
-                    let ident = syn::Ident::new("v", Span::call_site());
-                    token::Paren::default().surround(toks, |t| {
-                        ident.to_tokens(t);
+                        sequence(t, i, &expr, &self.macro_path);
                     });
-                    ident
-                };
+                    i += 1;
+                    continue;
+                }
+
+                let val = quote! { __delegate };
+                variant.pattern(&val).to_tokens(toks);
 
                 let expr = variant.wrapper_ty.as_ref().map_or_else(
-                    || val.to_token_stream(),
+                    || {
+                        variant.access().value(
+                            &val,
+                            ref_tok,
+                            mut_tok,
+                            &self.macro_path,
+                        )
+                    },
                     |as_ty| {
                         let ty = &variant.ty;
                         quote! {
@@ -400,6 +1294,7 @@ impl Definition {
                 token::Brace::default().surround(toks, |t| {
                     sequence(t, i, &expr, &self.macro_path);
                 });
+                i += 1;
             }
         });
 
@@ -414,20 +1309,45 @@ impl Definition {
         mut_tok: Option<token::Mut>,
     ) -> TokenStream {
         let macro_path = &self.macro_path;
-        let ident = field.ident();
+        let Some(ident) = field.ident() else {
+            let expr = match field {
+                Field::Value { path, .. } => zst_value(path, ref_tok, mut_tok),
+                Field::Via(_) => field.access().value(
+                    &quote! { self },
+                    ref_tok,
+                    mut_tok,
+                    macro_path,
+                ),
+                Field::Named { .. }
+                | Field::Unnamed { .. }
+                | Field::Proxied(_) => {
+                    unreachable!("only synthesized `Field`s have no `ident`")
+                }
+            };
+            return quote! { #macro_path::Either::Left(#expr) };
+        };
 
-        field.wrapper_ty().map_or_else(|| {
-            quote! { #macro_path::Either::Left(#ref_tok #mut_tok self. #ident) }
-        }, |as_ty| {
-            let ty = field.ty();
+        field.wrapper_ty().map_or_else(
+            || {
+                let expr = field.access().value(
+                    &quote! { #ref_tok #mut_tok self. #ident },
+                    ref_tok,
+                    mut_tok,
+                    macro_path,
+                );
+                quote! { #macro_path::Either::Left(#expr) }
+            },
+            |as_ty| {
+                let ty = field.ty();
 
-            quote! {
-                #macro_path::Either::Left(<
-                    #ref_tok #mut_tok #as_ty
-                        as ::core::convert::From< #ref_tok #mut_tok #ty >
-                >::from(#ref_tok #mut_tok self. #ident))
-            }
-        })
+                quote! {
+                    #macro_path::Either::Left(<
+                        #ref_tok #mut_tok #as_ty
+                            as ::core::convert::From< #ref_tok #mut_tok #ty >
+                    >::from(#ref_tok #mut_tok self. #ident))
+                }
+            },
+        )
     }
 }
 
@@ -442,19 +1362,108 @@ enum DelegatedTypes {
 }
 
 impl DelegatedTypes {
-    /// Returns an [`Iterator`] over these [`DelegatedTypes`].
-    fn types(&self) -> impl Iterator<Item = &syn::Type> {
+    /// Returns an [`Iterator`] over these [`DelegatedTypes`], along with the
+    /// way they're [`Access`]ed.
+    fn delegates(&self) -> impl Iterator<Item = (&syn::Type, Access<'_>)> {
         use itertools::Either::{Left, Right};
 
         match self {
             Self::Variants(variants) => Left(
-                variants
-                    .iter()
-                    .map(|var| var.wrapper_ty.as_ref().unwrap_or(&var.ty)),
+                variants.iter().filter(|var| var.with.is_none()).map(|var| {
+                    (var.wrapper_ty.as_ref().unwrap_or(&var.ty), var.access())
+                }),
             ),
-            Self::Field(field) => Right(iter::once(
+            Self::Field(field) => Right(iter::once((
                 field.wrapper_ty().unwrap_or_else(|| field.ty()),
-            )),
+                field.access(),
+            ))),
+        }
+    }
+
+    /// Returns an [`Iterator`] over these [`DelegatedTypes`].
+    fn types(&self) -> impl Iterator<Item = &syn::Type> {
+        self.delegates().map(|(ty, _)| ty)
+    }
+
+    /// Returns an [`Iterator`] over the [`Type`]s holding these
+    /// [`DelegatedTypes`], if they're not [`Access::Direct`]ed.
+    fn holders(&self) -> impl Iterator<Item = &syn::Type> {
+        self.delegates().filter_map(|(_, access)| access.holder())
+    }
+
+    /// Returns an [`Iterator`] over the pointer (or reference) [`Type`]s
+    /// holding these [`DelegatedTypes`], if they're [`Access::Deref`]ed.
+    fn pointers(&self) -> impl Iterator<Item = &syn::Type> {
+        self.delegates().filter_map(|(_, access)| {
+            if let Access::Deref(ptr) = access { Some(ptr) } else { None }
+        })
+    }
+
+    /// Returns the [`Type`]s these [`DelegatedTypes`] are converted into,
+    /// borrowed for the provided `lifetime`, if any.
+    fn converted_types(
+        &self,
+        lifetime: Option<&syn::Lifetime>,
+        is_mutable: bool,
+        macro_path: &MacroPath,
+    ) -> Vec<syn::Type> {
+        self.delegates()
+            .map(|(ty, access)| {
+                access.converted_ty(ty, lifetime, is_mutable, macro_path)
+            })
+            .collect()
+    }
+
+    /// Returns the `with(..)` argument of the `impl_for!` macro, matching the
+    /// enum variants to be delegated to `with` functions, if any.
+    fn with_arms(&self) -> Option<TokenStream> {
+        let Self::Variants(variants) = self else {
+            return None;
+        };
+
+        let arms = variants
+            .iter()
+            .filter_map(|var| {
+                let path = var.with.as_ref()?;
+                let pat = var.pattern(&quote! { __delegate });
+                Some(quote! { #pat => #path })
+            })
+            .collect::<Vec<_>>();
+        (!arms.is_empty()).then(|| quote! { , with( #( #arms ),* ) })
+    }
+
+    /// Returns the [`Type`] to delegate associated functions without receiver
+    /// to, along with the expression constructing `Self` from its
+    /// `__delegate` value, if any.
+    ///
+    /// Delegates behind smart pointers (or references) or locks are wrapped
+    /// via their [`From`] implementation.
+    fn default(&self) -> Option<(&syn::Type, TokenStream)> {
+        let binding = |ty: &syn::Type, access: Access<'_>| {
+            access.holder().map_or_else(
+                || quote! { __delegate },
+                |holder| {
+                    quote! {
+                        <#holder as ::core::convert::From<#ty>>
+                            ::from(__delegate)
+                    }
+                },
+            )
+        };
+
+        match self {
+            Self::Variants(variants) => {
+                let var = variants.iter().find(|var| var.default)?;
+                let binding = binding(&var.ty, var.access());
+                Some((&var.ty, var.pattern(&binding)))
+            }
+            Self::Field(field) => {
+                if field.wrapper_ty().is_some() || field.has_others() {
+                    return None;
+                }
+                let binding = binding(field.ty(), field.access());
+                Some((field.ty(), field.constructor(&binding)))
+            }
         }
     }
 }
@@ -474,6 +1483,16 @@ enum Field {
 
         /// Wrapper [`Type`] for external delegation.
         wrapper_ty: Option<syn::Type>,
+
+        /// [`Holder`] of the delegate to delegate through to its [`ty`]
+        /// target.
+        ///
+        /// [`ty`]: Field::ty
+        holder: Option<Holder>,
+
+        /// Indicator whether the struct has other fields besides this
+        /// [`Field`].
+        has_others: bool,
     },
 
     /// [`Field`] of tuple struct.
@@ -486,59 +1505,486 @@ enum Field {
 
         /// Wrapper [`Type`] for external delegation.
         wrapper_ty: Option<syn::Type>,
+
+        /// [`Holder`] of the delegate to delegate through to its [`ty`]
+        /// target.
+        ///
+        /// [`ty`]: Field::ty
+        holder: Option<Holder>,
+
+        /// Indicator whether the struct has other fields besides this
+        /// [`Field`].
+        has_others: bool,
+    },
+
+    /// Synthesized `as_value` of a struct without fields.
+    Value {
+        /// [`Path`] of the zero-sized type of this [`Field`].
+        ///
+        /// [`Path`]: syn::Path
+        path: syn::Path,
+
+        /// [`Type`] of this [`Field`].
+        ty: Box<syn::Type>,
     },
+
+    /// [`Field`] of a struct wrapped into a proxy type.
+    Proxied(Box<Self>),
+
+    /// Synthesized [`Field`] of a struct, obtained via accessors.
+    Via(Box<Via>),
 }
 
 impl Field {
-    /// Returns an [`Ident`] or an [`Index`] to access this [`Field`].
+    /// Creates a new [`Field`] from the provided `n`th [`syn::Field`] of a
+    /// struct and its [`InnerArgs`].
+    fn new(
+        field: &syn::Field,
+        n: usize,
+        args: Option<&InnerArgs>,
+        has_others: bool,
+    ) -> syn::Result<Self> {
+        if let Some(default) = args.and_then(|a| a.default.as_ref()) {
+            return Err(syn::Error::new(
+                default.span(),
+                "`default` attribute argument is allowed only on enum \
+                 variants",
+            ));
+        }
+        if let Some(with) = args.and_then(|a| a.with.as_ref()) {
+            return Err(syn::Error::new(
+                with.span(),
+                "`with` attribute argument is allowed only on enum variants",
+            ));
+        }
+        if let Some(value) = args.and_then(|a| a.as_value.as_ref()) {
+            return Err(syn::Error::new(
+                value.span(),
+                "`as_value` attribute argument is allowed only on structs \
+                 without fields or unit enum variants",
+            ));
+        }
+        if let Some(span) = args.and_then(|a| a.via.span()) {
+            return Err(syn::Error::new(
+                span,
+                "`via` attribute argument is allowed only on structs and enum \
+                 variants",
+            ));
+        }
+        let wrapper_ty = args.and_then(|a| a.r#as.clone());
+        let holder = args.and_then(|a| Holder::new(a, &field.ty));
+        let ty = Box::new(
+            holder.as_ref().map_or_else(|| field.ty.clone(), Holder::target),
+        );
+
+        Ok(match field.ident.as_ref() {
+            Some(ident) => Self::Named {
+                ident: ident.clone(),
+                ty,
+                wrapper_ty,
+                holder,
+                has_others,
+            },
+            None => Self::Unnamed {
+                index: syn::Index { span: field.span(), ..n.into() },
+                ty,
+                wrapper_ty,
+                holder,
+                has_others,
+            },
+        })
+    }
+
+    /// Returns an [`Ident`] or an [`Index`] to access this [`Field`], if it's
+    /// not synthesized.
     ///
     /// [`Ident`]: struct@syn::Ident
-    fn ident(&self) -> TokenStream {
+    fn ident(&self) -> Option<TokenStream> {
         match self {
-            Self::Named { ident, .. } => ident.to_token_stream(),
-            Self::Unnamed { index, .. } => index.to_token_stream(),
+            Self::Named { ident, .. } => Some(ident.to_token_stream()),
+            Self::Unnamed { index, .. } => Some(index.to_token_stream()),
+            Self::Value { .. } | Self::Via(_) => None,
+            Self::Proxied(field) => {
+                let ident = field.ident()?;
+                Some(quote! { 0.#ident })
+            }
         }
     }
 
+    /// Returns a constructor of the struct with this [`Field`] set to the
+    /// provided `binding`.
+    fn constructor(&self, binding: &TokenStream) -> TokenStream {
+        self.ident().map_or_else(
+            || quote! { Self {} },
+            |ident| quote! { Self { #ident: #binding } },
+        )
+    }
+
     /// Returns a [`Type`] of this [`Field`].
-    const fn ty(&self) -> &syn::Type {
+    fn ty(&self) -> &syn::Type {
         match self {
-            Self::Named { ty, .. } | Self::Unnamed { ty, .. } => ty,
+            Self::Named { ty, .. }
+            | Self::Unnamed { ty, .. }
+            | Self::Value { ty, .. } => ty,
+            Self::Proxied(field) => field.ty(),
+            Self::Via(via) => &via.ty,
         }
     }
 
     /// Returns wrapper [`Type`] for external delegation, if any.
-    const fn wrapper_ty(&self) -> Option<&syn::Type> {
+    fn wrapper_ty(&self) -> Option<&syn::Type> {
         match self {
             Self::Named { wrapper_ty, .. }
             | Self::Unnamed { wrapper_ty, .. } => wrapper_ty.as_ref(),
+            Self::Value { .. } | Self::Via(_) => None,
+            Self::Proxied(field) => field.wrapper_ty(),
+        }
+    }
+
+    /// Returns the way the delegate is [`Access`]ed from this [`Field`].
+    fn access(&self) -> Access<'_> {
+        match self {
+            Self::Named { holder, .. } | Self::Unnamed { holder, .. } => {
+                holder.as_ref().map_or(Access::Direct, Holder::access)
+            }
+            Self::Value { .. } => Access::Direct,
+            Self::Proxied(field) => field.access(),
+            Self::Via(via) => Access::Via(via),
+        }
+    }
+
+    /// Indicates whether the struct has other fields besides this [`Field`],
+    /// so it cannot be constructed from this [`Field`] only.
+    ///
+    /// Proxy types and types delegating via accessors are never constructed
+    /// from their [`Field`]s.
+    const fn has_others(&self) -> bool {
+        match self {
+            Self::Named { has_others, .. }
+            | Self::Unnamed { has_others, .. } => *has_others,
+            Self::Value { .. } => false,
+            Self::Proxied(_) | Self::Via(_) => true,
         }
     }
 }
 
-impl TryFrom<&mut syn::Fields> for Field {
+/// Way a delegate is accessed from its [`Field`] (or [`Variant`]).
+#[derive(Clone, Copy, Debug)]
+enum Access<'a> {
+    /// Delegate is the [`Field`] itself.
+    Direct,
+
+    /// Delegate is behind the smart pointer (or reference) [`Type`] of the
+    /// [`Field`].
+    Deref(&'a syn::Type),
+
+    /// Delegate is guarded by the lock [`Type`] of the [`Field`].
+    Lock(&'a syn::Type),
+
+    /// Delegate is obtained [`Via`] accessors.
+    Via(&'a Via),
+}
+
+impl<'a> Access<'a> {
+    /// Indicates whether this [`Access`] is [`Access::Direct`].
+    const fn is_direct(self) -> bool {
+        matches!(self, Self::Direct)
+    }
+
+    /// Returns the [`Type`] holding the delegate, unless this [`Access`] is
+    /// [`Access::Direct`].
+    const fn holder(self) -> Option<&'a syn::Type> {
+        match self {
+            Self::Direct => None,
+            Self::Deref(ty) | Self::Lock(ty) => Some(ty),
+            Self::Via(via) => Some(&via.source),
+        }
+    }
+
+    /// Returns the [`Type`] the provided delegate `ty` is converted into with
+    /// this [`Access`], borrowed for the provided `lifetime`, if any.
+    ///
+    /// Delegates behind smart pointers (or references) are converted according
+    /// to the `DerefConvert` trait, the ones guarded by locks are borrowed as
+    /// `Locked` types, to be locked on each call, while the ones obtained
+    /// [`Via`] accessors are converted into `NotOwned` or `NotMutable` types,
+    /// if the accessors for them are not specified.
+    fn converted_ty(
+        self,
+        ty: &syn::Type,
+        lifetime: Option<&syn::Lifetime>,
+        is_mutable: bool,
+        macro_path: &MacroPath,
+    ) -> syn::Type {
+        let mut_tok = is_mutable.then(token::Mut::default);
+        match (self, lifetime) {
+            (Self::Direct | Self::Lock(_), None) => ty.clone(),
+            (Self::Direct, Some(lt)) => parse_quote! { & #lt #mut_tok #ty },
+            (Self::Deref(_) | Self::Via(_), Some(lt)) if !is_mutable => {
+                parse_quote! { & #lt #ty }
+            }
+            (Self::Lock(lock), Some(lt)) if !is_mutable => {
+                parse_quote! { #macro_path::Locked<#lt, #lock> }
+            }
+            (Self::Lock(_), Some(lt)) => parse_quote! { & #lt mut #ty },
+            (Self::Deref(ptr), None) => parse_quote! {
+                <#ptr as #macro_path::DerefConvert>::Owned
+            },
+            (Self::Deref(ptr), Some(lt)) => parse_quote! {
+                <#ptr as #macro_path::DerefConvert>::RefMut<#lt>
+            },
+            (Self::Via(via), None) => {
+                let source = &via.source;
+                if via.get_owned.is_some() {
+                    ty.clone()
+                } else {
+                    parse_quote! { #macro_path::NotOwned<#source> }
+                }
+            }
+            (Self::Via(via), Some(lt)) => {
+                let source = &via.source;
+                if via.get_mut.is_some() {
+                    parse_quote! { & #lt mut #ty }
+                } else {
+                    parse_quote! { #macro_path::NotMutable<#lt, #source> }
+                }
+            }
+        }
+    }
+
+    /// Generates an expression converting the provided `expr` of the
+    /// [`Field`] (or [`Variant`]) into its delegate with this [`Access`],
+    /// borrowed according to the provided `ref_tok` and `mut_tok`.
+    fn value(
+        self,
+        expr: &TokenStream,
+        ref_tok: Option<token::And>,
+        mut_tok: Option<token::Mut>,
+        macro_path: &MacroPath,
+    ) -> TokenStream {
+        match (self, ref_tok, mut_tok) {
+            (Self::Direct, ..) => expr.clone(),
+            (Self::Deref(ptr), None, _) => quote! {
+                <#ptr as #macro_path::DerefConvert>::deref_owned(#expr)
+            },
+            (Self::Deref(ptr), Some(_), None) => quote! {
+                <#ptr as ::core::ops::Deref>::deref(#expr)
+            },
+            (Self::Deref(ptr), Some(_), Some(_)) => quote! {
+                <#ptr as #macro_path::DerefConvert>::deref_ref_mut(#expr)
+            },
+            (Self::Lock(lock), None, _) => quote! {
+                <#lock as #macro_path::Lock>::into_inner(#expr)
+            },
+            (Self::Lock(_), Some(_), None) => quote! {
+                #macro_path::Locked(#expr)
+            },
+            (Self::Lock(lock), Some(_), Some(_)) => quote! {
+                <#lock as #macro_path::Lock>::get_mut(#expr)
+            },
+            (Self::Via(via), None, _) => via.get_owned.as_ref().map_or_else(
+                || quote! { #macro_path::NotOwned(#expr) },
+                |get| quote! { #get(#expr) },
+            ),
+            (Self::Via(via), Some(_), None) => {
+                let get = &via.get;
+                quote! { #get(#expr) }
+            }
+            (Self::Via(via), Some(_), Some(_)) => {
+                via.get_mut.as_ref().map_or_else(
+                    || quote! { #macro_path::NotMutable(#expr) },
+                    |get| quote! { #get(#expr) },
+                )
+            }
+        }
+    }
+}
+
+/// Accessors to obtain a delegate from, instead of a [`Field`].
+#[derive(Clone, Debug)]
+struct Via {
+    /// [`Path`] of the accessor borrowing the delegate.
+    get: syn::Path,
+
+    /// [`Path`] of the accessor borrowing the delegate mutably, if any.
+    get_mut: Option<syn::Path>,
+
+    /// [`Path`] of the accessor moving the delegate out, if any.
+    get_owned: Option<syn::Path>,
+
+    /// [`Type`] of the delegate.
+    ty: syn::Type,
+
+    /// [`Type`] the accessors are called on.
+    source: syn::Type,
+}
+
+/// [`Type`] holding a delegate in a [`Field`] (or [`Variant`]), to delegate
+/// through to its target.
+#[derive(Clone, Debug)]
+enum Holder {
+    /// Smart pointer (or reference) marked with the `deref` attribute
+    /// argument.
+    Pointer(syn::Type),
+
+    /// Lock marked with the `lock` attribute argument.
+    Lock(syn::Type),
+}
+
+impl Holder {
+    /// Creates a new [`Holder`] of the provided field `ty`, if any is specified
+    /// by the provided [`InnerArgs`].
+    fn new(args: &InnerArgs, ty: &syn::Type) -> Option<Self> {
+        if args.deref.is_some() {
+            Some(Self::Pointer(ty.clone()))
+        } else if args.lock.is_some() {
+            Some(Self::Lock(ty.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the target [`Type`] of this [`Holder`].
+    fn target(&self) -> syn::Type {
+        match self {
+            Self::Pointer(ptr) => {
+                parse_quote! { <#ptr as ::core::ops::Deref>::Target }
+            }
+            Self::Lock(lock) => {
+                let macro_path = MacroPath::default();
+                parse_quote! { <#lock as #macro_path::Lock>::Target }
+            }
+        }
+    }
+
+    /// Returns the way the delegate is [`Access`]ed through this [`Holder`].
+    const fn access(&self) -> Access<'_> {
+        match self {
+            Self::Pointer(ptr) => Access::Deref(ptr),
+            Self::Lock(lock) => Access::Lock(lock),
+        }
+    }
+}
+
+/// Generates an expression converting the owned `__delegate` back into the
+/// [`Type`] holding it with the provided [`Access`].
+fn from_owned(access: Access<'_>, macro_path: &MacroPath) -> TokenStream {
+    match access {
+        Access::Direct | Access::Via(_) => quote! { __delegate },
+        Access::Deref(ptr) => quote! {
+            <#ptr as #macro_path::DerefConvert>::from_owned(__delegate)
+        },
+        Access::Lock(lock) => quote! {
+            <#lock as #macro_path::Lock>::from_inner(__delegate)
+        },
+    }
+}
+
+/// Generates an expression of the zero-sized `as_value` type at the provided
+/// [`Path`], borrowed according to the provided `ref_tok` and `mut_tok`.
+///
+/// [`Path`]: syn::Path
+fn zst_value(
+    path: &syn::Path,
+    ref_tok: Option<token::And>,
+    mut_tok: Option<token::Mut>,
+) -> TokenStream {
+    let mut expr = path.clone();
+    for seg in &mut expr.segments {
+        if let syn::PathArguments::AngleBracketed(args) = &mut seg.arguments {
+            args.colon2_token = Some(token::PathSep::default());
+        }
+    }
+    if ref_tok.is_none() {
+        return expr.into_token_stream();
+    }
+
+    // References to zero-sized types may dangle, as they never access memory.
+    let as_ref = if mut_tok.is_some() {
+        quote! { as_mut }
+    } else {
+        quote! { as_ref }
+    };
+    quote! {{
+        const {
+            ::core::assert!(
+                ::core::mem::size_of::<#path>() == 0,
+                "`as_value` type must be zero-sized",
+            );
+        }
+        _ = ::core::mem::ManuallyDrop::new(#expr);
+        #[allow(unsafe_code, reason = "macro expansion")]
+        unsafe { ::core::ptr::NonNull::<#path>::dangling().#as_ref() }
+    }}
+}
+
+/// [`Field`]s of a struct to delegate to.
+#[derive(Clone, Debug)]
+struct Fields {
+    /// [`Field`] to delegate the struct to, if any.
+    delegated: Option<Field>,
+
+    /// [`Field`]s to delegate the specified traits to, separately from the
+    /// struct itself.
+    derived: Vec<(Field, Vec<DeriveTrait>)>,
+}
+
+impl TryFrom<&mut syn::Fields> for Fields {
     type Error = syn::Error;
 
     fn try_from(fields: &mut syn::Fields) -> Result<Self, Self::Error> {
         let span = fields.span();
-        let field = fields.iter_mut().at_most_one().ok().flatten().ok_or_else(
-            || syn::Error::new(span, "struct must have exactly one field"),
-        )?;
-        let args = InnerArgs::from_attrs(field.attrs.as_mut())?;
-        let wrapper_ty = args.and_then(|a| a.r#as);
+        if fields.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "struct without fields must have `as_value` attribute argument",
+            ));
+        }
+        let has_others = fields.len() > 1;
+
+        let mut delegated = None;
+        let mut derived = Vec::new();
+        for (n, field) in fields.iter_mut().enumerate() {
+            let mut args = InnerArgs::from_attrs(field.attrs.as_mut())?;
+            let derive = args
+                .as_mut()
+                .map(|a| mem::take(&mut a.derive))
+                .unwrap_or_default();
+            let to = args.as_ref().and_then(|a| a.to.as_ref());
+            let is_delegated = to.is_some() || !has_others;
+            if !is_delegated && derive.is_empty() && args.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`#[delegate(..)]` attribute is allowed only on the \
+                     field marked with `#[delegate(to)]` or having \
+                     `derive(..)` attribute argument",
+                ));
+            }
+            if let (Some(to), Some(_)) = (to, &delegated) {
+                return Err(syn::Error::new(
+                    to.span(),
+                    "only one struct field can be marked with \
+                     `#[delegate(to)]`",
+                ));
+            }
 
-        Ok(match field.ident.as_ref() {
-            Some(ident) => Self::Named {
-                ident: ident.clone(),
-                ty: Box::new(field.ty.clone()),
-                wrapper_ty,
-            },
-            None => Self::Unnamed {
-                index: syn::Index { index: 0, span: field.span() },
-                ty: Box::new(field.ty.clone()),
-                wrapper_ty,
-            },
-        })
+            let field = Field::new(field, n, args.as_ref(), has_others)?;
+            if !derive.is_empty() {
+                derived.push((field.clone(), derive));
+            }
+            if is_delegated {
+                delegated = Some(field);
+            }
+        }
+        if delegated.is_none() && derived.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "struct with multiple fields must have exactly one field \
+                 marked with `#[delegate(to)]`",
+            ));
+        }
+
+        Ok(Self { delegated, derived })
     }
 }
 
@@ -550,44 +1996,285 @@ struct Variant {
     /// [`Ident`]: struct@syn::Ident
     ident: syn::Ident,
 
-    /// [`Ident`] of the field contained in this variant.
+    /// [`Member`] of the field to delegate to in this [`Variant`].
     ///
-    /// [`None`] means variant field is unnamed.
+    /// [`None`] means this [`Variant`] has no fields, and delegates to the
+    /// synthesized [`value`].
     ///
-    /// [`Ident`]: struct@syn::Ident
-    field_ident: Option<syn::Ident>,
+    /// [`Member`]: syn::Member
+    /// [`value`]: Variant::value
+    field: Option<syn::Member>,
+
+    /// Indicator whether this [`Variant`] has other fields besides the
+    /// delegated one.
+    has_others: bool,
 
     /// [`Type`] of this [`Variant`].
     ty: syn::Type,
 
     /// Wrapper [`Type`] for external delegation.
     wrapper_ty: Option<syn::Type>,
+
+    /// [`Holder`] of the delegate to delegate through to its [`ty`] target.
+    ///
+    /// [`ty`]: Variant::ty
+    holder: Option<Holder>,
+
+    /// Accessors to obtain the delegate from the field of this [`Variant`]
+    /// instead of delegating to the field itself.
+    via: Option<Via>,
+
+    /// Indicator whether associated functions without receiver are delegated
+    /// to this [`Variant`].
+    default: bool,
+
+    /// [`Path`] to the functions to be called for this [`Variant`] instead of
+    /// delegating to it.
+    with: Option<syn::Path>,
+
+    /// [`Path`] of the zero-sized type synthesized for this unit [`Variant`]
+    /// to delegate to.
+    value: Option<syn::Path>,
+}
+
+impl Variant {
+    /// Returns the [`Member`] and the [`Type`] of the field to delegate to
+    /// among the provided non-empty `fields` of a [`Variant`].
+    ///
+    /// [`Member`]: syn::Member
+    fn delegated_field(
+        fields: &mut syn::Fields,
+    ) -> syn::Result<(syn::Member, syn::Type)> {
+        let has_others = fields.len() > 1;
+        let mut marked = None;
+        for (n, field) in fields.iter_mut().enumerate() {
+            let field_args = InnerArgs::from_attrs(field.attrs.as_mut())?;
+            let to = match &field_args {
+                Some(InnerArgs {
+                    r#as: None,
+                    default: None,
+                    with: None,
+                    as_value: None,
+                    to: Some(to),
+                    deref: None,
+                    lock: None,
+                    via: ViaArgs { via: None, via_mut: None, via_owned: None },
+                    derive,
+                }) if derive.is_empty() => Some(to),
+                Some(_) => {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        "only `to` attribute argument is allowed on enum \
+                         variant fields",
+                    ));
+                }
+                None => None,
+            };
+            if to.is_none() && has_others {
+                continue;
+            }
+            if let (Some(to), Some(_)) = (to, &marked) {
+                return Err(syn::Error::new(
+                    to.span(),
+                    "only one enum variant field can be marked with \
+                     `#[delegate(to)]`",
+                ));
+            }
+            let member = field.ident.clone().map_or_else(
+                || {
+                    syn::Member::Unnamed(syn::Index {
+                        span: field.span(),
+                        ..n.into()
+                    })
+                },
+                syn::Member::Named,
+            );
+            marked = Some((member, field.ty.clone()));
+        }
+        marked.ok_or_else(|| {
+            syn::Error::new(
+                fields.span(),
+                "enum variant with multiple fields must have exactly one field \
+                 marked with `#[delegate(to)]`",
+            )
+        })
+    }
+
+    /// Returns the way the delegate is [`Access`]ed from this [`Variant`].
+    const fn access(&self) -> Access<'_> {
+        match (&self.holder, &self.via) {
+            (Some(holder), _) => holder.access(),
+            (None, Some(via)) => Access::Via(via),
+            (None, None) => Access::Direct,
+        }
+    }
+
+    /// Returns a pattern (or a constructor, unless this [`Variant`] has other
+    /// fields) of this [`Variant`] with its delegated field bound to the
+    /// provided `binding`.
+    fn pattern(&self, binding: &TokenStream) -> TokenStream {
+        let ident = &self.ident;
+        match &self.field {
+            None => quote! { Self::#ident {} },
+            Some(syn::Member::Unnamed(_)) if !self.has_others => {
+                quote! { Self::#ident(#binding) }
+            }
+            Some(field) => {
+                let rest = self.has_others.then(|| quote! { , .. });
+                quote! { Self::#ident { #field: #binding #rest } }
+            }
+        }
+    }
 }
 
 impl TryFrom<&mut syn::Variant> for Variant {
     type Error = syn::Error;
 
     fn try_from(variant: &mut syn::Variant) -> Result<Self, Self::Error> {
-        let args = InnerArgs::from_attrs(variant.attrs.as_mut())?;
+        let mut args = InnerArgs::from_attrs(variant.attrs.as_mut())?;
+        let default = args.as_ref().is_some_and(|a| a.default.is_some());
+        let with = args.as_ref().and_then(|a| a.with.clone());
+        if let Some(to) = args.as_ref().and_then(|a| a.to.as_ref()) {
+            return Err(syn::Error::new(
+                to.span(),
+                "`to` attribute argument is allowed only on struct fields",
+            ));
+        }
+        if let Some(derive) = args.as_ref().and_then(|a| a.derive.first()) {
+            return Err(syn::Error::new(
+                derive.path.span(),
+                "`derive` attribute argument is allowed only on struct fields, \
+                 consider placing it on the enum itself",
+            ));
+        }
 
-        variant
-            .fields
-            .iter()
-            .at_most_one()
-            .ok()
-            .flatten()
-            .map(|f| Self {
+        if let Some(path) = args.as_ref().and_then(|a| a.as_value.clone()) {
+            if !variant.fields.is_empty() {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "`as_value` attribute argument is allowed only on unit \
+                     enum variants",
+                ));
+            }
+            return Ok(Self {
                 ident: variant.ident.clone(),
-                field_ident: f.ident.clone(),
-                ty: f.ty.clone(),
-                wrapper_ty: args.and_then(|a| a.r#as),
+                field: None,
+                has_others: false,
+                ty: syn::Type::Path(syn::TypePath {
+                    qself: None,
+                    path: path.clone(),
+                }),
+                wrapper_ty: None,
+                holder: None,
+                via: None,
+                default,
+                with: None,
+                value: Some(path),
+            });
+        }
+        if variant.fields.is_empty() {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "unit enum variant must have `as_value` attribute argument",
+            ));
+        }
+
+        let (member, ty) = Self::delegated_field(&mut variant.fields)?;
+        let has_others = variant.fields.len() > 1;
+        if has_others && default {
+            return Err(syn::Error::new(
+                args.as_ref()
+                    .map_or_else(Span::call_site, |a| a.default.span()),
+                "`default` attribute argument is not allowed on enum variants \
+                 with multiple fields, as they cannot be constructed from the \
+                 delegated field only",
+            ));
+        }
+
+        let holder = args.as_ref().and_then(|a| Holder::new(a, &ty));
+        let via = args
+            .as_mut()
+            .map(|a| mem::take(&mut a.via))
+            .unwrap_or_default()
+            .into_via(ty.clone())?;
+        let ty = match (&holder, &via) {
+            (Some(holder), _) => holder.target(),
+            (None, Some(via)) => via.ty.clone(),
+            (None, None) => ty,
+        };
+
+        Ok(Self {
+            ident: variant.ident.clone(),
+            field: Some(member),
+            has_others,
+            ty,
+            wrapper_ty: args.and_then(|a| a.r#as),
+            holder,
+            via,
+            default,
+            with,
+            value: None,
+        })
+    }
+}
+
+/// Inherent method to be delegated, specified in the `methods { .. }`
+/// attribute argument.
+#[derive(Clone, Debug)]
+struct Method {
+    /// Declaration of this [`Method`].
+    item: syn::ForeignItemFn,
+
+    /// [`Ident`]s of the arguments of this [`Method`], except its receiver.
+    ///
+    /// [`Ident`]: struct@syn::Ident
+    args: Vec<syn::Ident>,
+
+    /// Reference of the receiver of this [`Method`], if any.
+    ref_tok: Option<token::And>,
+
+    /// Mutability of the receiver of this [`Method`], if it's a reference.
+    mut_tok: Option<token::Mut>,
+}
+
+impl Parse for Method {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let item = input.parse::<syn::ForeignItemFn>()?;
+        let sig = &item.sig;
+
+        let receiver = sig.receiver().filter(|r| r.colon_token.is_none());
+        let Some(receiver) = receiver else {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "only methods with `self`, `&self` or `&mut self` receivers \
+                 can be delegated",
+            ));
+        };
+        let ref_tok = receiver.reference.as_ref().map(|(and, _)| *and);
+        let mut_tok = receiver.mutability.filter(|_| ref_tok.is_some());
+
+        let args = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::FnArg::Typed(arg) => Some(arg),
+                syn::FnArg::Receiver(_) => None,
             })
-            .ok_or_else(|| {
-                syn::Error::new(
-                    variant.fields.span(),
-                    "enum variant must have exactly one field",
-                )
+            .map(|arg| {
+                if let syn::Pat::Ident(pat) = arg.pat.as_ref() {
+                    if pat.by_ref.is_none() && pat.subpat.is_none() {
+                        return Ok(pat.ident.clone());
+                    }
+                }
+                Err(syn::Error::new(
+                    arg.pat.span(),
+                    "only identifier patterns are allowed in arguments of \
+                     delegated methods",
+                ))
             })
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Self { item, args, ref_tok, mut_tok })
     }
 }
 
@@ -600,6 +2287,10 @@ struct DeriveTrait {
     /// Type of external trait wrapper.
     wrapper_ty: Option<syn::Path>,
 
+    /// Methods of the trait to keep the default implementation of, instead of
+    /// delegating them.
+    skip: Vec<syn::Ident>,
+
     /// [`Generics`] to be used in `impl` block.
     generics: Option<syn::Generics>,
 
@@ -632,6 +2323,32 @@ impl Parse for DeriveTrait {
             })
             .transpose()?;
         let path = input.parse()?;
+        let mut skip = Vec::new();
+        if input.peek(token::Paren) {
+            let args;
+            _ = syn::parenthesized!(args in input);
+
+            while !args.is_empty() {
+                let arg = args.parse::<syn::Ident>()?;
+                if arg != "skip" {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "unexpected argument",
+                    ));
+                }
+                let methods;
+                _ = syn::parenthesized!(methods in args);
+                skip.extend(
+                    Punctuated::<syn::Ident, token::Comma>::parse_terminated(
+                        &methods,
+                    )?,
+                );
+
+                if args.parse::<Option<token::Comma>>()?.is_none() {
+                    break;
+                }
+            }
+        }
         let wrapper_ty = input
             .peek(token::As)
             .then(|| {
@@ -641,7 +2358,7 @@ impl Parse for DeriveTrait {
             .transpose()?;
         let where_clause = syn::WhereClause::parse_thrifty_opt(input)?;
 
-        Ok(Self { path, wrapper_ty, generics, where_clause })
+        Ok(Self { path, wrapper_ty, skip, generics, where_clause })
     }
 }
 
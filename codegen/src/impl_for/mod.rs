@@ -4,18 +4,26 @@ mod util;
 
 use std::mem;
 
+use itertools::Itertools as _;
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 #[cfg(doc)]
 use syn::{Generics, Type};
 use syn::{
     parse::{Parse, ParseStream},
-    parse_quote, token,
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    token,
+    visit::Visit as _,
     visit_mut::VisitMut as _,
 };
 
-use self::util::{ElideLifetimes as _, GenericBinder, GenericsExt as _};
-use crate::MacroPath;
+use self::util::{
+    ElideLifetimes as _, GenericBinder, GenericsExt as _, PatBindings,
+    ReplaceSelfCalls,
+};
+use crate::{MacroPath, util::TypeExt as _};
 
 /// Definition of `impl_for!` macro expansion.
 #[derive(Debug)]
@@ -44,6 +52,46 @@ pub(super) struct Definition {
     /// [`template`]: Definition::template
     wrapper_ty: syn::Path,
 
+    /// [`Type`] implementing the `Convert` trait on behalf of the [`self_ty`],
+    /// if it differs from the [`self_ty`] itself.
+    ///
+    /// [`self_ty`]: Definition::self_ty
+    via: Option<syn::Type>,
+
+    /// [`Type`]s the [`self_ty`] delegates the trait to, if known.
+    ///
+    /// [`self_ty`]: Definition::self_ty
+    delegated: Vec<syn::Type>,
+
+    /// [`Type`] the [`self_ty`] delegates associated functions without
+    /// receiver to, if any.
+    ///
+    /// [`self_ty`]: Definition::self_ty
+    default: Option<syn::Type>,
+
+    /// Methods of the trait to keep the default implementation of, instead of
+    /// delegating them.
+    skip: Vec<syn::Ident>,
+
+    /// Pointer (or reference) [`Type`]s holding the [`delegated`] ones, which
+    /// are accessed by dereferencing.
+    ///
+    /// [`delegated`]: Definition::delegated
+    pointers: Vec<syn::Type>,
+
+    /// [`WithArm`]s matching the [`self_ty`] variants to be delegated to
+    /// functions instead of the [`delegated`] types.
+    ///
+    /// [`delegated`]: Definition::delegated
+    /// [`self_ty`]: Definition::self_ty
+    with: Vec<WithArm>,
+
+    /// Associated types of the trait being sum types, so may differ for the
+    /// [`delegated`] types.
+    ///
+    /// [`delegated`]: Definition::delegated
+    sum_types: Vec<syn::Ident>,
+
     /// [`Path`] to the macro definitions.
     ///
     /// [`Path`]: syn::Path
@@ -69,19 +117,75 @@ impl Parse for Definition {
             generics.where_clause = Some(where_clause);
         }
 
+        let mut via = None;
+        let mut delegated = Vec::new();
+        let mut default = None;
+        let mut skip = Vec::new();
+        let mut with = Vec::new();
+        let mut pointers = Vec::new();
+        if input.parse::<Option<token::Semi>>()?.is_some() {
+            while !input.is_empty() {
+                let arg = input.parse::<syn::Ident>()?;
+                let args;
+                _ = syn::parenthesized!(args in input);
+
+                if arg == "delegated" {
+                    delegated.extend(
+                        Punctuated::<_, token::Comma>::parse_terminated(&args)?,
+                    );
+                } else if arg == "via" {
+                    via = Some(args.parse()?);
+                } else if arg == "default" {
+                    default = Some(args.parse()?);
+                } else if arg == "with" {
+                    with.extend(
+                        Punctuated::<_, token::Comma>::parse_terminated(&args)?,
+                    );
+                } else if arg == "skip" {
+                    skip.extend(
+                        Punctuated::<_, token::Comma>::parse_terminated(&args)?,
+                    );
+                } else if arg == "deref" {
+                    pointers.extend(
+                        Punctuated::<_, token::Comma>::parse_terminated(&args)?,
+                    );
+                } else {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "unexpected argument",
+                    ));
+                }
+
+                if input.parse::<Option<token::Comma>>()?.is_none() {
+                    break;
+                }
+            }
+        }
+
         let mut this = Self {
             template,
             generics,
             trait_path,
             self_ty,
             wrapper_ty,
+            via,
+            delegated,
+            default,
+            skip,
+            pointers,
+            with,
+            sum_types: Vec::new(),
             macro_path: MacroPath::default(),
         };
 
+        this.skip_methods()?;
+        this.check_default()?;
+        this.extract_markers();
         this.specify_type();
         this.specify_trait()?;
         this.specify_methods();
         this.specify_generics();
+        this.match_with_arms()?;
 
         Ok(this)
     }
@@ -89,6 +193,11 @@ impl Parse for Definition {
 
 impl ToTokens for Definition {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        // Assertions go first, so their errors are reported before the ones of
+        // the impl.
+        self.assert_pointers_deref().to_tokens(tokens);
+        self.assert_assoc_types_agree().to_tokens(tokens);
+        self.assert_consts_agree().to_tokens(tokens);
         self.template.to_tokens(tokens);
     }
 }
@@ -100,6 +209,88 @@ impl Definition {
         self.wrapper_ty == parse_quote! { #macro_path ::Wrapper }
     }
 
+    /// Removes the [`skip`]ped methods from the template, so the default
+    /// implementations of the trait are used for them.
+    ///
+    /// [`skip`]: Definition::skip
+    fn skip_methods(&mut self) -> syn::Result<()> {
+        for ident in &self.skip {
+            let len = self.template.items.len();
+            self.template.items.retain(
+                |i| !matches!(i, syn::ImplItem::Fn(m) if &m.sig.ident == ident),
+            );
+            if self.template.items.len() == len {
+                let trait_path = &self.trait_path;
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "`{ident}` is not a delegated method of `{}`",
+                        quote! { #trait_path },
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the [`self_ty`] has a [`default`] delegate, if the trait has
+    /// associated functions without receiver and the [`delegated`] types are
+    /// known.
+    ///
+    /// [`default`]: Definition::default
+    /// [`delegated`]: Definition::delegated
+    /// [`self_ty`]: Definition::self_ty
+    fn check_default(&self) -> syn::Result<()> {
+        if self.delegated.is_empty() || self.default.is_some() {
+            return Ok(());
+        }
+
+        let fns = self
+            .template
+            .items
+            .iter()
+            .filter_map(|i| {
+                let syn::ImplItem::Fn(m) = i else {
+                    return None;
+                };
+                m.sig.receiver().is_none().then(|| format!("`{}`", m.sig.ident))
+            })
+            .collect::<Vec<_>>();
+        if fns.is_empty() {
+            return Ok(());
+        }
+
+        let self_ty = &self.self_ty;
+        let trait_path = &self.trait_path;
+        Err(syn::Error::new(
+            self_ty.span(),
+            format!(
+                "`{}` has no default delegate for associated functions of `{}` \
+                 without receiver: {}. Consider marking one of its variants \
+                 with `#[delegate(default)]`.",
+                quote! { #self_ty },
+                quote! { #trait_path },
+                fns.join(", "),
+            ),
+        ))
+    }
+
+    /// Extracts associated types marked as sum types from the template,
+    /// removing the marking `#[delegate(..)]` attributes.
+    fn extract_markers(&mut self) {
+        let is_marker =
+            |attr: &syn::Attribute| attr.path().is_ident("delegate");
+
+        for i in &mut self.template.items {
+            if let syn::ImplItem::Type(t) = i {
+                if t.attrs.iter().any(is_marker) {
+                    t.attrs.retain(|attr| !is_marker(attr));
+                    self.sum_types.push(t.ident.clone());
+                }
+            }
+        }
+    }
+
     /// Replaces template's `Self` [`Type`] with the specified one.
     fn specify_type(&mut self) {
         self.template.self_ty = self.self_ty.clone().into();
@@ -128,6 +319,12 @@ impl Definition {
                 if let syn::ImplItem::Fn(m) = i {
                     binder.visit_impl_item_fn_mut(m);
                 }
+                if let syn::ImplItem::Type(t) = i {
+                    binder.visit_impl_item_type_mut(t);
+                }
+                if let syn::ImplItem::Const(c) = i {
+                    binder.visit_impl_item_const_mut(c);
+                }
             }
         }
 
@@ -140,7 +337,7 @@ impl Definition {
         let wrapper_ty = &self.wrapper_ty;
         let is_external = !self.is_local_trait();
 
-        let mut self_ty = self.self_ty.clone();
+        let mut self_ty = self.via.as_ref().unwrap_or(&self.self_ty).clone();
         self_ty.elide_lifetimes();
 
         let mut trait_path = self.trait_path.clone();
@@ -190,40 +387,57 @@ impl Definition {
             }
         };
 
-        let replace_selfcall = |block: &mut syn::Block| {
-            let Some(syn::Stmt::Expr(
-                syn::Expr::Call(syn::ExprCall { func, .. }),
-                _,
-            )) = block.stmts.first_mut()
-            else {
-                return;
-            };
-
-            let syn::Expr::Path(syn::ExprPath {
-                qself: Some(qself), path, ..
-            }) = func.as_mut()
-            else {
-                return;
-            };
-
-            // 1. Replace `<WrapperTemplate as TraitTemplate>::trait_method`
-            //    with `<WrapperTemplate as Trait>::trait_method`.
+        let replace_qpath = |qself: &mut syn::QSelf, path: &mut syn::Path| {
+            // 1. Replace `<WrapperTemplate as TraitTemplate>::trait_item`
+            //    with `<WrapperTemplate as Trait>::trait_item`.
             let orig_path = mem::replace(path, trait_path.clone());
             path.segments
                 .extend(orig_path.segments.into_iter().skip(qself.position));
 
-            // 2. Replace `<WrapperTemplate as Trait>::trait_method`
-            //    with `<Wrapper as Trait>::trait_method`.
+            // 2. Replace `<WrapperTemplate as Trait>::trait_item`
+            //    with `<Wrapper as Trait>::trait_item`, unless it's a
+            //    `<Self as Trait>::trait_item`.
+            let is_self = matches!(
+                qself.ty.as_ref(),
+                syn::Type::Path(syn::TypePath { qself: None, path: ty_path })
+                    if ty_path.is_ident("Self"),
+            );
+            let ty =
+                if is_self { qself.ty.clone() } else { wrapper.clone().into() };
             *qself = syn::QSelf {
                 lt_token: qself.lt_token,
-                ty: wrapper.clone().into(),
+                ty,
                 position: trait_path.segments.len(),
                 as_token: qself.as_token,
                 gt_token: qself.gt_token,
             };
         };
 
+        let replace_selfcalls = |block: &mut syn::Block| {
+            let mut visitor = ReplaceSelfCalls { replace: &replace_qpath };
+            visitor.visit_block_mut(block);
+        };
+
         for i in &mut self.template.items {
+            if let syn::ImplItem::Type(syn::ImplItemType {
+                ty: syn::Type::Path(syn::TypePath { qself: Some(qself), path }),
+                ..
+            }) = i
+            {
+                replace_qpath(qself, path);
+            }
+
+            if let syn::ImplItem::Const(syn::ImplItemConst {
+                expr:
+                    syn::Expr::Path(syn::ExprPath {
+                        qself: Some(qself), path, ..
+                    }),
+                ..
+            }) = i
+            {
+                replace_qpath(qself, path);
+            }
+
             if let syn::ImplItem::Fn(m) = i {
                 // Replace only for external traits because local ones
                 // uses `Self` without fully qualified paths.
@@ -241,7 +455,7 @@ impl Definition {
                     }
                 }
 
-                replace_selfcall(&mut m.block);
+                replace_selfcalls(&mut m.block);
             }
         }
     }
@@ -251,4 +465,324 @@ impl Definition {
     fn specify_generics(&mut self) {
         self.template.generics = self.generics.clone();
     }
+
+    /// Prepends the template methods with matching of the [`with`] arms,
+    /// calling the functions of the matched arm instead of delegating.
+    ///
+    /// `Self` arguments holding the [`with`] variants are considered to be
+    /// mismatched with the receiver not holding them.
+    ///
+    /// Traits with [`sum_types`] are rejected, as the values returned by the
+    /// [`with`] functions are not wrapped into them.
+    ///
+    /// [`sum_types`]: Definition::sum_types
+    /// [`with`]: Definition::with
+    fn match_with_arms(&mut self) -> syn::Result<()> {
+        let Some(first) = self.with.first() else {
+            return Ok(());
+        };
+
+        let trait_path = &self.trait_path;
+        if let Some(sum) = self.sum_types.first() {
+            return Err(syn::Error::new(
+                first.path.span(),
+                format!(
+                    "`{}` cannot be delegated to `with` functions, as its \
+                     `{sum}` associated type is a sum type",
+                    quote! { #trait_path },
+                ),
+            ));
+        }
+        for i in &mut self.template.items {
+            let syn::ImplItem::Fn(m) = i else {
+                continue;
+            };
+            let method_name = &m.sig.ident;
+
+            let Some(receiver) = m.sig.receiver() else {
+                continue;
+            };
+            let is_opaque = m.sig.asyncness.is_some()
+                || matches!(
+                    &m.sig.output,
+                    syn::ReturnType::Type(_, ty)
+                        if matches!(**ty, syn::Type::ImplTrait(_)),
+                );
+            if receiver.colon_token.is_some() || is_opaque {
+                return Err(syn::Error::new(
+                    first.path.span(),
+                    format!(
+                        "`{method_name}` method of `{}` cannot be delegated to \
+                         `with` functions, as it has a typed receiver or \
+                         returns `impl Trait`",
+                        quote! { #trait_path },
+                    ),
+                ));
+            }
+
+            let inputs = m
+                .sig
+                .inputs
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::FnArg::Typed(t) => Some(&t.pat),
+                    syn::FnArg::Receiver(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let unsafety = m.sig.unsafety.map(|_| quote! { unsafe });
+
+            let arms =
+                self.with.iter().map(|WithArm { pat, binding, path }| {
+                    quote! {
+                        #pat => {
+                            #[allow(unused_unsafe, reason = "macro expansion")]
+                            return #unsafety {
+                                #path::#method_name(#binding, #( #inputs ),*)
+                            };
+                        }
+                    }
+                });
+
+            let self_args = m.sig.inputs.iter().filter_map(|arg| match arg {
+                syn::FnArg::Typed(t) if t.ty.is_self() => Some(&t.pat),
+                syn::FnArg::Typed(_) | syn::FnArg::Receiver(_) => None,
+            });
+            let mismatches = self_args
+                .flat_map(|arg| {
+                    self.with.iter().map(move |WithArm { pat, .. }| {
+                        quote! { ::core::matches!(&#arg, #pat) }
+                    })
+                })
+                .collect::<Vec<_>>();
+            let mismatch_check = (!mismatches.is_empty()).then(|| {
+                let mismatch_fn =
+                    format_ident!("__delegate_mismatch_{method_name}");
+                quote! {
+                    if #( #mismatches )||* {
+                        return <Self as #trait_path>
+                            ::#mismatch_fn(self, #( #inputs ),*);
+                    }
+                }
+            });
+
+            let stmts = &m.block.stmts;
+            m.block = parse_quote! {{
+                #[allow(unreachable_patterns, reason = "macro expansion")]
+                match self {
+                    #( #arms )*
+                    _ => {}
+                }
+                #mismatch_check
+                #( #stmts )*
+            }};
+        }
+
+        Ok(())
+    }
+
+    /// Generates assertions that the pointee of the [`pointers`] can be moved
+    /// out (or borrowed mutably), if the trait has methods taking `self` (or
+    /// `&mut self`), pointing to the pointers unable to do that.
+    ///
+    /// [`pointers`]: Definition::pointers
+    fn assert_pointers_deref(&self) -> TokenStream {
+        let (mut has_owned, mut has_ref_mut) = (false, false);
+        for item in &self.template.items {
+            if let syn::ImplItem::Fn(m) = item {
+                if let Some(rec) = m.sig.receiver() {
+                    // `Pin`ned receivers are explicitly typed.
+                    if rec.colon_token.is_none() {
+                        has_owned |= rec.reference.is_none();
+                        has_ref_mut |=
+                            rec.reference.is_some() && rec.mutability.is_some();
+                    }
+                }
+            }
+        }
+        if self.pointers.is_empty() || !(has_owned || has_ref_mut) {
+            return TokenStream::new();
+        }
+
+        let macro_path = &self.macro_path;
+        let (impl_gens, _, where_clause) = self.generics.split_for_impl();
+
+        let use_owned = has_owned.then(|| {
+            quote! { use #macro_path::AssertDerefOwned as __AssertDerefOwned; }
+        });
+        let use_ref_mut = has_ref_mut.then(|| {
+            quote! {
+                use #macro_path::AssertDerefMutable as __AssertDerefMutable;
+            }
+        });
+        let assertions = self.pointers.iter().flat_map(|ptr| {
+            let owned = has_owned.then(|| {
+                quote_spanned! { ptr.span() => __AssertDerefOwned<#ptr> }
+            });
+            let ref_mut = has_ref_mut.then(|| {
+                quote_spanned! { ptr.span() => __AssertDerefMutable<#ptr> }
+            });
+            owned.into_iter().chain(ref_mut)
+        });
+
+        quote! {
+            #[automatically_derived]
+            const _: () = {
+                // Imported, so the whole asserting type is spanned to the
+                // pointer.
+                #use_owned
+                #use_ref_mut
+
+                #[allow(dead_code, reason = "macro expansion")]
+                fn __delegate_pointers_deref #impl_gens (
+                    #( _: #assertions, )*
+                ) #where_clause {}
+            };
+        }
+    }
+
+    /// Generates assertions that associated types of the trait (except the
+    /// [`sum_types`]) are the same for all the [`delegated`] types, pointing to
+    /// the first disagreeing one.
+    ///
+    /// [`delegated`]: Definition::delegated
+    /// [`sum_types`]: Definition::sum_types
+    fn assert_assoc_types_agree(&self) -> TokenStream {
+        let assoc_types = self
+            .template
+            .items
+            .iter()
+            .filter_map(|i| {
+                if let syn::ImplItem::Type(t) = i {
+                    (!self.sum_types.contains(&t.ident)).then_some(&t.ident)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        let Some((first, rest)) = self.delegated.split_first() else {
+            return TokenStream::new();
+        };
+        if assoc_types.is_empty() || rest.is_empty() {
+            return TokenStream::new();
+        }
+
+        let trait_path = &self.trait_path;
+        let macro_path = &self.macro_path;
+        let (impl_gens, _, where_clause) = self.generics.split_for_impl();
+
+        let assertions = rest.iter().flat_map(|ty| {
+            assoc_types.iter().map(move |assoc| {
+                // Re-spanned, so the error points to the disagreeing type.
+                let assoc = syn::Ident::new(&assoc.to_string(), ty.span());
+                quote_spanned! { ty.span() =>
+                    __AssertSameType<
+                        <#ty as #trait_path>::#assoc,
+                        <#first as #trait_path>::#assoc,
+                    >
+                }
+            })
+        });
+
+        quote! {
+            #[automatically_derived]
+            const _: () = {
+                // Imported, so the whole asserting type is spanned to the
+                // disagreeing one.
+                use #macro_path::AssertSameType as __AssertSameType;
+
+                #[allow(dead_code, reason = "macro expansion")]
+                fn __delegate_assoc_types_agree #impl_gens (
+                    #( _: #assertions, )*
+                ) #where_clause {}
+            };
+        }
+    }
+
+    /// Generates assertions that associated constants of the trait have the
+    /// same values for all the [`delegated`] types, pointing to the first
+    /// disagreeing one.
+    ///
+    /// Assertions are generated only for non-generic impls, as generic ones
+    /// are checked once the constant is evaluated.
+    ///
+    /// [`delegated`]: Definition::delegated
+    fn assert_consts_agree(&self) -> TokenStream {
+        let Some((first, rest)) = self.delegated.split_first() else {
+            return TokenStream::new();
+        };
+        if !self.generics.params.is_empty() {
+            return TokenStream::new();
+        }
+
+        let trait_path = &self.trait_path;
+        let macro_path = &self.macro_path;
+
+        let consts = self
+            .template
+            .items
+            .iter()
+            .filter_map(|i| {
+                if let syn::ImplItem::Const(c) = i { Some(c) } else { None }
+            })
+            .collect::<Vec<_>>();
+
+        let assertions = rest.iter().flat_map(|ty| {
+            consts.iter().map(move |c| {
+                let ident = &c.ident;
+                let eq = c.ty.const_eq(
+                    &quote! { <#first as #trait_path>::#ident },
+                    &quote! { <#ty as #trait_path>::#ident },
+                    macro_path,
+                );
+                let msg = format!(
+                    "delegates have different values of `{ident}` associated \
+                     constant",
+                );
+
+                quote_spanned! { ty.span() =>
+                    const _: () = ::core::assert!(#eq, #msg);
+                }
+            })
+        });
+
+        quote! { #( #assertions )* }
+    }
+}
+
+/// Arm of the `with(..)` argument, matching a variant to be delegated to the
+/// functions of the [`Path`] instead.
+///
+/// [`Path`]: syn::Path
+#[derive(Debug)]
+struct WithArm {
+    /// Pattern matching the variant, binding its field.
+    pat: syn::Pat,
+
+    /// [`Ident`] the variant field is bound to by the [`pat`]tern.
+    ///
+    /// [`Ident`]: struct@syn::Ident
+    /// [`pat`]: WithArm::pat
+    binding: syn::Ident,
+
+    /// [`Path`] to the functions to be called for the matched variant.
+    ///
+    /// [`Path`]: syn::Path
+    path: syn::Path,
+}
+
+impl Parse for WithArm {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let pat = syn::Pat::parse_single(input)?;
+        _ = input.parse::<token::FatArrow>()?;
+        let path = input.parse()?;
+
+        // The binding is reused as is, because of its hygiene.
+        let mut bindings = PatBindings::default();
+        bindings.visit_pat(&pat);
+        let binding = bindings.0.into_iter().exactly_one().map_err(|_err| {
+            syn::Error::new(pat.span(), "expected exactly one binding")
+        })?;
+
+        Ok(Self { pat, binding, path })
+    }
 }
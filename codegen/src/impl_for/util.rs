@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use quote::ToTokens as _;
 use syn::{
     parse_quote,
+    visit::Visit,
     visit_mut::{self, VisitMut},
 };
 
@@ -230,3 +231,39 @@ impl VisitMut for ReplaceLifetimes<'_> {
         visit_mut::visit_lifetime_mut(self, i);
     }
 }
+
+/// Visitor replacing the qualified paths (like `<Self as Trait>::method`) of
+/// the calls in template method bodies.
+pub(super) struct ReplaceSelfCalls<'a, F> {
+    /// Function replacing the qualified path.
+    pub(super) replace: &'a F,
+}
+
+impl<F> VisitMut for ReplaceSelfCalls<'_, F>
+where
+    F: Fn(&mut syn::QSelf, &mut syn::Path),
+{
+    fn visit_expr_call_mut(&mut self, i: &mut syn::ExprCall) {
+        if let syn::Expr::Path(syn::ExprPath {
+            qself: Some(qself), path, ..
+        }) = i.func.as_mut()
+        {
+            (self.replace)(qself, path);
+        }
+
+        visit_mut::visit_expr_call_mut(self, i);
+    }
+}
+
+/// Collector of the [`Ident`]s bound by a [`Pat`]tern.
+///
+/// [`Ident`]: struct@syn::Ident
+/// [`Pat`]: syn::Pat
+#[derive(Debug, Default)]
+pub(super) struct PatBindings(pub(super) Vec<syn::Ident>);
+
+impl Visit<'_> for PatBindings {
+    fn visit_pat_ident(&mut self, i: &syn::PatIdent) {
+        self.0.push(i.ident.clone());
+    }
+}
@@ -3,9 +3,8 @@
 mod util;
 
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{HashSet, hash_map::DefaultHasher},
     hash::{Hash as _, Hasher as _},
-    iter,
 };
 
 use itertools::Itertools as _;
@@ -21,10 +20,13 @@ use syn::{
     token,
 };
 
-use self::util::{GenericsExt as _, SignatureExt as _};
+use self::util::{
+    GenericsExt as _, ReceiverExt as _, SignatureExt as _, TypeExt as _,
+    WherePredicateExt as _,
+};
 use crate::{
     MacroPath,
-    util::{GenericsExt as _, WhereClauseExt as _},
+    util::{GenericsExt as _, TypeExt as _, WhereClauseExt as _},
 };
 
 /// Arguments of `#[delegate]` macro expansion on traits.
@@ -90,6 +92,12 @@ pub(super) struct Definition {
     /// [`Generics`] of the trait.
     generics: syn::Generics,
 
+    /// Associated types of the trait.
+    assoc_types: Vec<AssocType>,
+
+    /// Associated constants of the trait.
+    consts: Vec<AssocConst>,
+
     /// Methods with `self` receiver.
     methods_owned: Vec<syn::TraitItemFn>,
 
@@ -99,6 +107,21 @@ pub(super) struct Definition {
     /// Methods with `&mut self` receiver.
     methods_ref_mut: Vec<syn::TraitItemFn>,
 
+    /// Methods with `self: Pin<&mut Self>` receiver.
+    methods_pin_mut: Vec<syn::TraitItemFn>,
+
+    /// Associated functions without receiver.
+    methods_static: Vec<syn::TraitItemFn>,
+
+    /// Names of the methods returning `impl Trait` (or being `async`).
+    opaque_methods: HashSet<syn::Ident>,
+
+    /// Names of the methods having `Self` arguments.
+    binary_methods: HashSet<syn::Ident>,
+
+    /// Names of the methods returning `Self`.
+    self_returning_methods: HashSet<syn::Ident>,
+
     /// Types for deriving trait on.
     delegate_for: Vec<ForTy>,
 
@@ -120,6 +143,18 @@ pub(super) struct Definition {
     /// [`Ident`]: struct@syn::Ident
     ref_mut_trait_ident: syn::Ident,
 
+    /// [`Ident`] for generated trait, that contains only methods with
+    /// `self: Pin<&mut Self>` receiver.
+    ///
+    /// [`Ident`]: struct@syn::Ident
+    pin_mut_trait_ident: syn::Ident,
+
+    /// [`Ident`] for generated type, summing the opaque types returned by
+    /// different delegates.
+    ///
+    /// [`Ident`]: struct@syn::Ident
+    opaque_sum_ident: syn::Ident,
+
     /// [`Ident`] for generated macro, that implements trait for provided type.
     ///
     /// [`Ident`]: struct@syn::Ident
@@ -155,24 +190,25 @@ impl ToTokens for Definition {
         self.generate_binds().to_tokens(tokens);
         self.assign_types_to_binds().to_tokens(tokens);
 
+        self.generate_opaque_sum().to_tokens(tokens);
+
         self.generate_owned_trait().to_tokens(tokens);
-        self.impl_owned_trait_for_either().to_tokens(tokens);
+        self.impl_owned_trait_for_either(false).to_tokens(tokens);
         self.impl_owned_trait_for_void().to_tokens(tokens);
 
-        self.generate_ref_trait(false).to_tokens(tokens);
-        self.impl_ref_trait_for_either(false).to_tokens(tokens);
-        self.impl_ref_trait_for_void(false).to_tokens(tokens);
-
-        self.generate_ref_trait(true).to_tokens(tokens);
-        self.impl_ref_trait_for_either(true).to_tokens(tokens);
-        self.impl_ref_trait_for_void(true).to_tokens(tokens);
+        for kind in [RefReceiver::Shared, RefReceiver::Mut, RefReceiver::PinMut]
+        {
+            self.generate_ref_trait(kind).to_tokens(tokens);
+            self.impl_ref_trait_for_either(kind, false, false)
+                .to_tokens(tokens);
+            self.impl_ref_trait_for_void(kind).to_tokens(tokens);
+        }
+        self.impl_ref_trait_for_locked().to_tokens(tokens);
 
         self.blanket_impl_for_wrapper_type().to_tokens(tokens);
         self.impl_macro_for_delegated_trait().to_tokens(tokens);
 
         self.impl_trait_for().to_tokens(tokens);
-
-        self.generate_self_bound_assertions().to_tokens(tokens);
     }
 }
 
@@ -186,54 +222,182 @@ impl Definition {
         let args = syn::parse2::<Args>(args)?;
         let macro_path = MacroPath::default();
 
-        let def_item = args.r#as.as_ref().map_or_else(
-            || Item::Definition(Box::new(item.clone())),
-            |path| Item::External(path.clone()),
-        );
         let item_hash = {
             let mut hasher = DefaultHasher::new();
             item.to_token_stream().to_string().hash(&mut hasher);
             hasher.finish()
         };
 
+        let mut assoc_types = Vec::new();
+        let mut consts = Vec::new();
+        let mut accessors = Vec::new();
         let mut methods_owned = Vec::new();
         let mut methods_ref = Vec::new();
         let mut methods_ref_mut = Vec::new();
-
-        for i in &item.items {
+        let mut methods_pin_mut = Vec::new();
+        let mut methods_static = Vec::new();
+        let mut opaque_methods = HashSet::new();
+        let mut binary_methods = HashSet::new();
+        let mut self_returning_methods = HashSet::new();
+        let mut mismatch_fallbacks = Vec::new();
+
+        for i in &mut item.items {
             match i {
-                syn::TraitItem::Fn(m) => match m.sig.receiver() {
-                    Some(syn::Receiver {
-                        reference: Some(_),
-                        mutability: Some(_),
-                        colon_token: None,
-                        ..
-                    }) => methods_ref_mut.push(m.clone()),
-                    Some(syn::Receiver {
-                        reference: Some(_),
-                        mutability: None,
-                        colon_token: None,
-                        ..
-                    }) => methods_ref.push(m.clone()),
-                    Some(syn::Receiver {
-                        reference: None,
-                        colon_token: None,
-                        ..
-                    }) => methods_owned.push(m.clone()),
-                    Some(syn::Receiver { colon_token: Some(_), .. }) | None => {
+                syn::TraitItem::Fn(m) => {
+                    let method_args = MethodArgs::from_attrs(&mut m.attrs)?;
+                    if let Some(skip) =
+                        method_args.as_ref().and_then(|a| a.skip.as_ref())
+                    {
+                        // External traits are checked for the default
+                        // implementation by the compiler, once implemented.
+                        if m.default.is_none() && args.r#as.is_none() {
+                            return Err(syn::Error::new(
+                                skip.span(),
+                                "methods marked with `#[delegate(skip)]` must \
+                                 have a default implementation",
+                            ));
+                        }
+                        continue;
+                    }
+
+                    let mut m = m.clone();
+                    if Self::parse_opaque_output(&mut m)? {
+                        _ = opaque_methods.insert(m.sig.ident.clone());
+                    }
+                    if let Some(fallback) = Self::parse_binary_method(
+                        &m,
+                        method_args.and_then(|a| a.mismatch),
+                        args.r#as.is_some(),
+                    )? {
+                        _ = binary_methods.insert(m.sig.ident.clone());
+                        mismatch_fallbacks.push(syn::TraitItem::Fn(fallback));
+                    }
+                    if m.sig.returns_self() && m.sig.receiver().is_some() {
+                        _ = self_returning_methods.insert(m.sig.ident.clone());
+                    }
+
+                    match m.sig.receiver() {
+                        Some(syn::Receiver {
+                            reference: Some(_),
+                            mutability: Some(_),
+                            colon_token: None,
+                            ..
+                        }) => methods_ref_mut.push(m.clone()),
+                        Some(syn::Receiver {
+                            reference: Some(_),
+                            mutability: None,
+                            colon_token: None,
+                            ..
+                        }) => methods_ref.push(m.clone()),
+                        Some(syn::Receiver {
+                            reference: None,
+                            colon_token: None,
+                            ..
+                        }) => methods_owned.push(m.clone()),
+                        Some(r) if ReceiverPointer::parse(r).is_some() => {
+                            methods_owned.push(m.clone());
+                        }
+                        Some(r) if r.pinned_reference().is_some() => {
+                            methods_pin_mut.push(m.clone());
+                        }
+                        Some(syn::Receiver {
+                            colon_token: Some(_), ..
+                        }) => {
+                            return Err(syn::Error::new(
+                                m.sig.inputs.span(),
+                                "only `self: Box<Self>`, `self: Rc<Self>`, \
+                                 `self: Arc<Self>` and `self: Pin<&mut Self>` \
+                                 typed receivers are supported",
+                            ));
+                        }
+                        None => methods_static.push(m.clone()),
+                    }
+                }
+                syn::TraitItem::Type(ty) => {
+                    if !ty.generics.params.is_empty() {
                         return Err(syn::Error::new(
-                            m.span(),
-                            "all trait method must have an untyped receiver",
+                            ty.generics.span(),
+                            "generic associated types are not supported yet",
                         ));
                     }
-                },
-                syn::TraitItem::Type(_)
-                | syn::TraitItem::Const(_)
-                | syn::TraitItem::Macro(_)
-                | syn::TraitItem::Verbatim(_) => {
+                    let ty_args = AssocTypeArgs::from_attrs(&mut ty.attrs)?;
+                    let (sum, supertrait) =
+                        ty_args.map_or((None, None), |a| (a.sum, a.supertrait));
+                    if let Some(path) = &supertrait {
+                        if args.r#as.is_none() {
+                            return Err(syn::Error::new(
+                                path.span(),
+                                "`supertrait` argument is allowed only for \
+                                 associated types of external traits",
+                            ));
+                        }
+                    }
+                    assoc_types.push(AssocType {
+                        item: ty.clone(),
+                        sum,
+                        supertrait,
+                    });
+                }
+                syn::TraitItem::Const(c) => {
+                    let accessor = AssocConstArgs::from_attrs(&mut c.attrs)?
+                        .map(|attr| {
+                            attr.accessor.unwrap_or_else(|| {
+                                format_ident!(
+                                    "{}",
+                                    c.ident.to_string().to_lowercase(),
+                                    span = c.ident.span(),
+                                )
+                            })
+                        });
+                    let c = AssocConst { item: c.clone() };
+                    match accessor {
+                        // Delegates may differ in constants with accessors, so
+                        // delegating types use their default values instead.
+                        Some(accessor) => {
+                            if args.r#as.is_some() {
+                                return Err(syn::Error::new(
+                                    accessor.span(),
+                                    "accessors of associated constants are \
+                                     not supported for external traits",
+                                ));
+                            }
+                            if c.item.default.is_none() {
+                                return Err(syn::Error::new(
+                                    c.item.ident.span(),
+                                    "associated constants with accessors \
+                                     should have a default value, used by \
+                                     delegating types",
+                                ));
+                            }
+                            accessors.push(c.accessor_method(&accessor));
+                        }
+                        // Shared values are asserted to agree in `const`
+                        // context, so only primitives can be compared.
+                        None if !c.item.ty.is_const_comparable() => {
+                            let hint = if args.r#as.is_some() {
+                                "are not supported for external traits"
+                            } else {
+                                "should be marked with `#[delegate(accessor)]`"
+                            };
+                            return Err(syn::Error::new_spanned(
+                                &c.item.ty,
+                                format!(
+                                    "associated constants shared by delegates \
+                                     should have an integer, `bool`, `char` \
+                                     or `&str` type, as their values are \
+                                     compared in `const` context, so others \
+                                     {hint}",
+                                ),
+                            ));
+                        }
+                        None => consts.push(c),
+                    }
+                }
+                syn::TraitItem::Macro(_) | syn::TraitItem::Verbatim(_) => {
                     return Err(syn::Error::new(
                         i.span(),
-                        "only trait methods with untyped receiver are allowed",
+                        "only associated types, associated constants and trait \
+                         methods with untyped receiver are allowed",
                     ));
                 }
                 i => {
@@ -245,11 +409,56 @@ impl Definition {
             }
         }
 
-        for m in methods_owned
+        item.items.extend(mismatch_fallbacks);
+
+        for method in accessors {
+            item.items.push(syn::TraitItem::Fn(method.clone()));
+            methods_ref.push(method);
+        }
+
+        let def_item = args.r#as.as_ref().map_or_else(
+            || Item::Definition(Box::new(item.clone())),
+            |path| Item::External(path.clone()),
+        );
+
+        let assoc_types_names =
+            assoc_types.iter().map(|ty| ty.item.ident.clone()).collect();
+        let sum_types_names = assoc_types
             .iter()
-            .chain(methods_ref.iter())
-            .chain(methods_ref_mut.iter())
+            .filter(|ty| ty.sum.is_some())
+            .map(|ty| ty.item.ident.clone())
+            .collect();
+        for m in methods_owned
+            .iter_mut()
+            .chain(methods_ref.iter_mut())
+            .chain(methods_ref_mut.iter_mut())
+            .chain(methods_pin_mut.iter_mut())
+            .chain(methods_static.iter_mut())
         {
+            m.sig.unqualify_self_assoc_types(&assoc_types_names);
+
+            let mut sig = m.sig.clone();
+            if sig.returns_self_assoc_type(&sum_types_names).is_some() {
+                // Associated functions are always delegated to the default
+                // delegate, so there is nothing to sum.
+                if sig.receiver().is_none() {
+                    return Err(syn::Error::new(
+                        sig.output.span(),
+                        "associated types marked with `#[delegate(sum)]` \
+                         cannot be returned from associated functions \
+                         without receiver",
+                    ));
+                }
+                sig.output = syn::ReturnType::Default;
+            }
+            if let Some(span) = sig.find_self_assoc_types(&sum_types_names) {
+                return Err(syn::Error::new(
+                    span,
+                    "associated types marked with `#[delegate(sum)]` may \
+                     only be used as a whole return type of trait methods",
+                ));
+            }
+
             let to_be_early_bounded = m.sig.to_be_early_bounded_lifetimes();
             if !to_be_early_bounded.is_empty() {
                 return Err(syn::Error::new(
@@ -283,6 +492,9 @@ impl Definition {
             format_ident!("__delegate_{}__DelegateRef", item.ident);
         let ref_mut_trait_ident =
             format_ident!("__delegate_{}__DelegateRefMut", item.ident);
+        let pin_mut_trait_ident =
+            format_ident!("__delegate_{}__DelegatePinMut", item.ident);
+        let opaque_sum_ident = format_ident!("__delegate_{}__Sum", item.ident);
         let impl_macro_ident = format_ident!(
             "__delegate_{}{item_hash}{}{}",
             item.ident,
@@ -304,13 +516,22 @@ impl Definition {
             unsafety: item.unsafety,
             ident: item.ident,
             generics: item.generics,
+            assoc_types,
+            consts,
             methods_owned,
             methods_ref,
             methods_ref_mut,
+            methods_pin_mut,
+            methods_static,
+            opaque_methods,
+            binary_methods,
+            self_returning_methods,
             delegate_for: args.r#for.into_iter().collect(),
             owned_trait_ident,
             ref_trait_ident,
             ref_mut_trait_ident,
+            pin_mut_trait_ident,
+            opaque_sum_ident,
             impl_macro_ident,
             wrapper_ty,
             scope_ident,
@@ -320,11 +541,148 @@ impl Definition {
         })
     }
 
+    /// Desugars the provided method, if it's `async`, and checks the bounds of
+    /// its `impl Trait` return type, if any.
+    ///
+    /// Returns `true` if the method returns an opaque type.
+    fn parse_opaque_output(method: &mut syn::TraitItemFn) -> syn::Result<bool> {
+        /// Traits supported in bounds of `impl Trait` return types.
+        const SUPPORTED: [&str; 8] = [
+            "Future",
+            "Iterator",
+            "DoubleEndedIterator",
+            "ExactSizeIterator",
+            "FusedIterator",
+            "Send",
+            "Sync",
+            "Unpin",
+        ];
+
+        if method.sig.desugar_async() {
+            // Default body is not needed for delegation, while not matching
+            // the desugared signature anymore.
+            method.default = None;
+            return Ok(true);
+        }
+
+        let Some(impl_trait) = method.sig.impl_trait_output() else {
+            return Ok(false);
+        };
+        for bound in &impl_trait.bounds {
+            let syn::TypeParamBound::Trait(t) = bound else {
+                continue;
+            };
+            let supported =
+                t.path.segments.last().is_some_and(|s| {
+                    SUPPORTED.iter().any(|name| s.ident == name)
+                });
+            if !supported {
+                return Err(syn::Error::new_spanned(
+                    t,
+                    "only `Future`, `Iterator`, `DoubleEndedIterator`, \
+                     `ExactSizeIterator`, `FusedIterator` and auto traits are \
+                     supported in `impl Trait` return types",
+                ));
+            }
+        }
+        Ok(true)
+    }
+
+    /// Checks the provided method having `Self` arguments (if it has any) to be
+    /// delegatable, and returns the fallback method to be called instead of it
+    /// when its receiver and `Self` arguments hold different variants.
+    ///
+    /// The fallback method calls the function specified by the provided
+    /// [`MethodArgs`], if any, or has the default body of the method
+    /// otherwise.
+    fn parse_binary_method(
+        method: &syn::TraitItemFn,
+        mismatch: Option<syn::Path>,
+        external: bool,
+    ) -> syn::Result<Option<syn::TraitItemFn>> {
+        let self_args = SelfArg::all_of(&method.sig);
+        if self_args.is_empty() {
+            return mismatch.map_or(Ok(None), |mismatch| {
+                Err(syn::Error::new_spanned(
+                    mismatch,
+                    "`mismatch` is allowed only for methods with `Self` \
+                     arguments",
+                ))
+            });
+        }
+
+        let err = |msg| Err(syn::Error::new(method.sig.span(), msg));
+        if external {
+            return err(
+                "methods with `Self` arguments are not supported for external \
+                 traits",
+            );
+        }
+        if method.sig.impl_trait_output().is_some() {
+            return err(
+                "methods with `Self` arguments cannot be `async` or return \
+                 `impl Trait`",
+            );
+        }
+        match method.sig.receiver() {
+            Some(syn::Receiver {
+                colon_token: None, reference: None, ..
+            }) => {
+                if self_args.iter().any(|(_, arg)| *arg != SelfArg::Owned) {
+                    return err(
+                        "methods with `self` receiver may only take `Self` \
+                         arguments by value",
+                    );
+                }
+            }
+            Some(syn::Receiver { colon_token: None, .. }) => {}
+            Some(_) | None => {
+                return err(
+                    "methods with `Self` arguments must have `self`, `&self` \
+                     or `&mut self` receiver",
+                );
+            }
+        }
+
+        let body: syn::Block = match (mismatch, &method.default) {
+            (Some(handler), _) => {
+                let (_, _, inputs) = method.sig.split_for_impl();
+                parse_quote! {{
+                    #handler(self, #( #inputs ),*)
+                }}
+            }
+            (None, Some(body)) => body.clone(),
+            (None, None) => {
+                return err(
+                    "methods with `Self` arguments should either have a \
+                     default implementation or `#[delegate(mismatch = path)]` \
+                     attribute, specifying what to call when variants differ",
+                );
+            }
+        };
+
+        let mut sig = method.sig.clone();
+        sig.ident = format_ident!("__delegate_mismatch_{}", sig.ident);
+        sig.generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::core::marker::Sized });
+
+        Ok(Some(parse_quote! {
+            #[doc(hidden)]
+            #sig #body
+        }))
+    }
+
     /// Defines a trait [`Item`].
     ///
     /// [`Item`] differs relying on the `#[delegate(as = ..)]` attribute:
     /// - For crate-local traits it's just a trait definition.
     /// - For external traits it's a newtype wrapper to implement the trait for.
+    ///
+    /// If the trait has methods with `self: Pin<&mut Self>` receiver, the
+    /// wrapper is projected structurally, so it's additionally asserted to not
+    /// implement `Drop`, and to be `Unpin` only if the wrapped type is.
     fn define_item(&self) -> TokenStream {
         match &self.item {
             Item::Definition(def) => {
@@ -338,14 +696,50 @@ impl Definition {
                 let vis = &self.vis;
                 let wrapper_ty = &self.wrapper_ty;
 
-                quote! {
+                // No traits are derived, as they may conflict with the
+                // delegated one.
+                let def = quote! {
                     #[automatically_derived]
-                    #[derive(Clone, Copy, Debug)]
                     #[doc(hidden)]
                     #[repr(transparent)]
-                    #vis struct #wrapper_ty <T>(T)
+                    #vis struct #wrapper_ty <T>(pub T)
                     where
                         T: ?::core::marker::Sized;
+                };
+                if self.methods_pin_mut.is_empty() {
+                    return def;
+                }
+
+                quote! {
+                    #def
+
+                    // Implementing `Unpin` manually conflicts with this impl,
+                    // so the wrapper cannot be `Unpin` while the wrapped type
+                    // is not.
+                    #[automatically_derived]
+                    impl<'__delegate, T> ::core::marker::Unpin
+                        for #wrapper_ty<T>
+                    where
+                        T: ?::core::marker::Sized,
+                        (
+                            ::core::marker::PhantomData<&'__delegate ()>,
+                            T,
+                        ): ::core::marker::Unpin,
+                    {}
+
+                    // Implementing `Drop` conflicts with the blanket impl
+                    // below.
+                    const _: () = {
+                        trait MustNotImplDrop {}
+
+                        #[allow(drop_bounds, reason = "macro expansion")]
+                        impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+
+                        impl<T> MustNotImplDrop for #wrapper_ty<T>
+                        where
+                            T: ?::core::marker::Sized,
+                        {}
+                    };
                 }
             }
         }
@@ -366,7 +760,7 @@ impl Definition {
                 let gens = {
                     let mut gens = self.generics.clone();
                     gens.append(&method_gens);
-                    gens.remove_self_ty_bounds();
+                    gens.remove_self_mentioning_bounds();
                     gens
                 };
                 let (impl_gens, _, where_clause) = gens.split_for_impl();
@@ -410,6 +804,12 @@ impl Definition {
                         #[doc(hidden)]
                         type Wrapper: ?::core::marker::Sized;
 
+                        #[doc(hidden)]
+                        fn wrap(delegate: __Delegate) -> Self::Wrapper
+                        where
+                            __Delegate: ::core::marker::Sized,
+                            Self::Wrapper: ::core::marker::Sized;
+
                         #( #assoc_ty )*
                     }
                 }
@@ -427,7 +827,7 @@ impl Definition {
                 let gens = {
                     let mut gens = self.generics.clone();
                     gens.append(&method_gens);
-                    gens.remove_self_ty_bounds();
+                    gens.remove_self_mentioning_bounds();
                     gens
                 };
                 let (impl_gens, _, where_clause) = gens.split_for_impl();
@@ -482,6 +882,15 @@ impl Definition {
                         #[doc(hidden)]
                         type Wrapper = #wrapper_ty <__Delegate>;
 
+                        #[doc(hidden)]
+                        fn wrap(delegate: __Delegate) -> Self::Wrapper
+                        where
+                            __Delegate: ::core::marker::Sized,
+                            Self::Wrapper: ::core::marker::Sized,
+                        {
+                            #wrapper_ty(delegate)
+                        }
+
                         #( #assoc_ty )*
                     }
                 }
@@ -504,7 +913,7 @@ impl Definition {
                     gens.params.push(
                         parse_quote! { __Delegate: ?::core::marker::Sized },
                     );
-                    gens.remove_self_ty_bounds();
+                    gens.remove_self_mentioning_bounds();
                     gens
                 };
                 let (impl_gens, _, where_clause) = gens.split_for_impl();
@@ -530,9 +939,28 @@ impl Definition {
     /// expanding nested `macro_rules!` macro expansion.
     fn assign_types_to_binds(&self) -> TokenStream {
         let macro_path = &self.macro_path;
+        // Associated types of supertraits cannot be qualified with the trait
+        // itself.
+        let assoc_types_names: HashSet<_> = self
+            .assoc_types
+            .iter()
+            .filter(|ty| ty.supertrait.is_none())
+            .map(|ty| ty.item.ident.clone())
+            .collect();
+        let supertraits_assoc_types =
+            self.assoc_types.iter().filter_map(|ty| {
+                let supertrait = ty.supertrait.as_ref()?;
+                Some((HashSet::from([ty.item.ident.clone()]), supertrait))
+            });
+
+        let trait_path: syn::Path = {
+            let orig_trait = self.item.path();
+            let (_, ty_gens, _) = self.generics.split_for_impl();
+            parse_quote! { #orig_trait #ty_gens }
+        };
 
         let impls = self.methods_types().enumerate().map(
-            |(seq_num, (method_gens, ty))| {
+            |(seq_num, (method_gens, mut ty))| {
                 let bind_ident = format_ident!("{}{seq_num}", &self.bind_ident);
 
                 let bind_gens = {
@@ -541,6 +969,7 @@ impl Definition {
                     gens.params.push(
                         parse_quote! { __Delegate: ?::core::marker::Sized },
                     );
+                    gens.remove_self_mentioning_bounds();
                     gens.replace_self_ty(&parse_quote! { __Delegate });
                     gens
                 };
@@ -548,6 +977,24 @@ impl Definition {
 
                 let impl_gens = {
                     let mut gens = bind_gens.clone();
+                    let delegate_ty = parse_quote! { __Delegate };
+                    let mut qualified = ty.qualify_self_assoc_types(
+                        &assoc_types_names,
+                        &delegate_ty,
+                        &trait_path,
+                    );
+                    for (names, supertrait) in supertraits_assoc_types.clone() {
+                        qualified |= ty.qualify_self_assoc_types(
+                            &names,
+                            &delegate_ty,
+                            supertrait,
+                        );
+                    }
+                    if qualified {
+                        gens.make_where_clause()
+                            .predicates
+                            .push(parse_quote! { __Delegate: #trait_path });
+                    }
                     gens.bound_type_to_lifetimes(&ty);
                     gens
                 };
@@ -568,17 +1015,180 @@ impl Definition {
         quote! { #( #impls )* }
     }
 
+    /// Generates a type summing the opaque types returned by the trait methods
+    /// from different delegates, if there are any.
+    ///
+    /// The type implements `Future` and `Iterator` traits family, being the
+    /// only ones supported in `impl Trait` return types.
+    fn generate_opaque_sum(&self) -> TokenStream {
+        if self.opaque_methods.is_empty() {
+            return TokenStream::new();
+        }
+
+        let sum = &self.opaque_sum_ident;
+        let iter_impls = self.opaque_sum_iter_impls();
+
+        quote! {
+            #[automatically_derived]
+            #[allow(non_camel_case_types, reason = "macro expansion")]
+            #[doc(hidden)]
+            enum #sum<__Left, __Right> {
+                Left(__Left),
+                Right(__Right),
+            }
+
+            #[automatically_derived]
+            impl<__Left, __Right> ::core::future::Future
+                for #sum<__Left, __Right>
+            where
+                __Left: ::core::future::Future,
+                __Right: ::core::future::Future<Output = __Left::Output>,
+            {
+                type Output = __Left::Output;
+
+                fn poll(
+                    self: ::core::pin::Pin<&mut Self>,
+                    cx: &mut ::core::task::Context<'_>,
+                ) -> ::core::task::Poll<Self::Output> {
+                    // SAFETY: Variants are structurally pinned, as this type
+                    //         neither implements `Drop`, nor `Unpin` manually.
+                    #[allow(unsafe_code, reason = "macro expansion")]
+                    unsafe {
+                        match ::core::pin::Pin::get_unchecked_mut(self) {
+                            Self::Left(f) => ::core::future::Future::poll(
+                                ::core::pin::Pin::new_unchecked(f),
+                                cx,
+                            ),
+                            Self::Right(f) => ::core::future::Future::poll(
+                                ::core::pin::Pin::new_unchecked(f),
+                                cx,
+                            ),
+                        }
+                    }
+                }
+            }
+
+            #iter_impls
+        }
+    }
+
+    /// Generates implementations of [`Iterator`] traits family for the type
+    /// summing the opaque types (see [`Self::generate_opaque_sum()`]).
+    fn opaque_sum_iter_impls(&self) -> TokenStream {
+        let sum = &self.opaque_sum_ident;
+
+        let either = |method: TokenStream| {
+            quote! {
+                match self {
+                    Self::Left(__delegate) => __delegate.#method,
+                    Self::Right(__delegate) => __delegate.#method,
+                }
+            }
+        };
+        let next = either(quote! { next() });
+        let size_hint = either(quote! { size_hint() });
+        let nth = either(quote! { nth(n) });
+        let fold = either(quote! { fold(init, f) });
+        let next_back = either(quote! { next_back() });
+        let len = either(quote! { len() });
+
+        quote! {
+            #[automatically_derived]
+            impl<__Left, __Right> ::core::iter::Iterator
+                for #sum<__Left, __Right>
+            where
+                __Left: ::core::iter::Iterator,
+                __Right: ::core::iter::Iterator<Item = __Left::Item>,
+            {
+                type Item = __Left::Item;
+
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    #next
+                }
+
+                fn size_hint(
+                    &self,
+                ) -> (usize, ::core::option::Option<usize>) {
+                    #size_hint
+                }
+
+                fn nth(
+                    &mut self,
+                    n: usize,
+                ) -> ::core::option::Option<Self::Item> {
+                    #nth
+                }
+
+                fn fold<__Acc, __F>(self, init: __Acc, f: __F) -> __Acc
+                where
+                    __F: ::core::ops::FnMut(__Acc, Self::Item) -> __Acc,
+                {
+                    #fold
+                }
+            }
+
+            #[automatically_derived]
+            impl<__Left, __Right> ::core::iter::DoubleEndedIterator
+             for #sum<__Left, __Right>
+            where
+                __Left: ::core::iter::DoubleEndedIterator,
+                __Right: ::core::iter::DoubleEndedIterator<
+                    Item = __Left::Item,
+                >,
+            {
+                fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                    #next_back
+                }
+            }
+
+            #[automatically_derived]
+            impl<__Left, __Right> ::core::iter::ExactSizeIterator
+             for #sum<__Left, __Right>
+            where
+                __Left: ::core::iter::ExactSizeIterator,
+                __Right: ::core::iter::ExactSizeIterator<
+                    Item = __Left::Item,
+                >,
+            {
+                fn len(&self) -> usize {
+                    #len
+                }
+            }
+
+            #[automatically_derived]
+            impl<__Left, __Right> ::core::iter::FusedIterator
+             for #sum<__Left, __Right>
+            where
+                __Left: ::core::iter::FusedIterator,
+                __Right: ::core::iter::FusedIterator<Item = __Left::Item>,
+            {}
+        }
+    }
+
     /// Generates a trait containing only methods with `self` receiver.
     fn generate_owned_trait(&self) -> TokenStream {
+        let vis = &self.vis;
         let owned_trait = &self.owned_trait_ident;
-        let generics = &self.generics;
-        let where_clause = &self.generics.where_clause;
-        let owned_methods = &self.methods_owned;
+        let generics = self.owned_trait_generics();
+        let where_clause = &generics.where_clause;
+        let assoc_types = self.assoc_types_decls();
+        let consts = self.consts.iter().map(|c| {
+            let ident = &c.item.ident;
+            let ty = &c.item.ty;
 
+            quote! { const #ident: #ty; }
+        });
+        let owned_methods = self.owned_trait_methods().map(|(m, _)| m);
+
+        // Visibility is inherited, so the associated types of public traits
+        // don't leak private ones.
         quote! {
             #[automatically_derived]
             #[allow(non_camel_case_types, reason = "macro expansion")]
-            trait #owned_trait #generics #where_clause {
+            #[doc(hidden)]
+            #vis trait #owned_trait #generics #where_clause {
+                #( #assoc_types )*
+                #( #consts )*
                 #( #owned_methods )*
             }
         }
@@ -586,58 +1196,100 @@ impl Definition {
 
     /// Implements a trait generated by the [`Self::generate_owned_trait()`]
     /// method for an `Either`.
-    fn impl_owned_trait_for_either(&self) -> TokenStream {
+    ///
+    /// If `last` is `true`, then implements it for an `Either` having a `Void`
+    /// on its right side (the last one in the `Either` chain).
+    fn impl_owned_trait_for_either(&self, last: bool) -> TokenStream {
         let macro_path = &self.macro_path;
         let orig_trait = self.item.path();
         let owned_trait = &self.owned_trait_ident;
 
         let (_, ty_gens, _) = self.generics.split_for_impl();
 
+        let left_assoc = quote! { <__Left as #orig_trait #ty_gens> };
+        let left_assoc_types = Self::left_assoc_types(&left_assoc);
+
         let generics = {
-            let mut gens = self.generics.clone();
+            let mut gens = self.owned_trait_generics();
 
-            let params: [syn::GenericParam; 2] =
-                [parse_quote! { __Left }, parse_quote! { __Right }];
-            gens.params.extend(params);
+            gens.params.push(parse_quote! { __Left });
+            gens.make_where_clause()
+                .predicates
+                .push(parse_quote! { __Left: #orig_trait #ty_gens });
 
-            let predicates: [syn::WherePredicate; 2] = [
-                parse_quote! { __Left: #orig_trait #ty_gens },
-                parse_quote! { __Right: #owned_trait #ty_gens },
-            ];
-            gens.make_where_clause().predicates.extend(predicates);
+            if !last {
+                let right_args = self.generics.to_arguments_with(
+                    self.assoc_types_bindings_with(&left_assoc_types, false),
+                );
+
+                gens.params.push(parse_quote! { __Right });
+                gens.make_where_clause()
+                    .predicates
+                    .push(parse_quote! { __Right: #owned_trait #right_args });
+            }
 
             gens
         };
         let (impl_gens, _, where_clause) = generics.split_for_impl();
 
-        let methods = self.methods_owned.iter().map(|m| {
+        let right_ty = if last {
+            quote! { #macro_path::Void }
+        } else {
+            quote! { __Right }
+        };
+
+        let right_assoc = quote! { <__Right as #owned_trait #ty_gens> };
+        let assoc_types = self.assoc_types_defs(
+            &left_assoc_types,
+            (!last).then_some(&right_assoc),
+        );
+        let consts =
+            self.consts_defs(&left_assoc, (!last).then_some(&right_assoc));
+
+        let methods = self.owned_trait_methods().map(|(m, pointer)| {
             let (signature, method_name, method_inputs) =
                 m.sig.split_for_impl();
             let method_inputs = method_inputs.collect::<Vec<_>>();
 
-            quote! {
-                #signature {
-                    match self {
-                        Self::Left(__delegate) => {
-                            <__Left as #orig_trait #ty_gens>::#method_name(
-                                __delegate, #( #method_inputs ),*
-                            )
-                        }
-                        Self::Right(__delegate) => {
-                            <__Right as #owned_trait #ty_gens>::#method_name(
-                                __delegate, #( #method_inputs ),*
-                            )
-                        }
-                    }
-                }
-            }
+            let left_receiver = pointer.map_or_else(
+                || quote! { __delegate },
+                |ptr| ptr.wrap_expr(&quote! { __delegate }),
+            );
+            let left_call = self.wrap_either_variant(
+                signature,
+                true,
+                last,
+                quote! {
+                    <__Left as #orig_trait #ty_gens>::#method_name(
+                        #left_receiver, #( #method_inputs ),*
+                    )
+                },
+            );
+            let right_call = if last {
+                quote! { match __delegate {} }
+            } else {
+                self.wrap_either_variant(
+                    signature,
+                    false,
+                    last,
+                    quote! {
+                        <__Right as #owned_trait #ty_gens>::#method_name(
+                            __delegate, #( #method_inputs ),*
+                        )
+                    },
+                )
+            };
+
+            self.either_method_impl(signature, &left_call, &right_call)
         });
 
         quote! {
             #[automatically_derived]
             impl #impl_gens #owned_trait #ty_gens
-             for #macro_path::Either<__Left, __Right> #where_clause
+             for #macro_path::Either<__Left, #right_ty> #where_clause
             {
+                #( #assoc_types )*
+                #( #consts )*
                 #( #methods )*
             }
         }
@@ -645,14 +1297,26 @@ impl Definition {
 
     /// Implements a trait generated by the [`Self::generate_owned_trait()`]
     /// method for a `Void`.
+    ///
+    /// A `Void` cannot resolve associated types and constants of the trait, nor
+    /// return opaque types, so, if there are any, the last `Either` in the
+    /// chain is implemented instead.
     fn impl_owned_trait_for_void(&self) -> TokenStream {
+        if !self.assoc_types.is_empty()
+            || !self.consts.is_empty()
+            || !self.opaque_methods.is_empty()
+        {
+            return self.impl_owned_trait_for_either(true);
+        }
+
         let macro_path = &self.macro_path;
         let owned_trait = &self.owned_trait_ident;
 
-        let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
+        let generics = self.owned_trait_generics();
+        let (impl_gens, ty_gens, where_clause) = generics.split_for_impl();
 
-        let methods = self.methods_owned.iter().map(|m| {
-            let signature = &m.sig;
+        let methods = self.owned_trait_methods().map(|(m, _)| {
+            let signature = m.sig;
 
             quote! {
                 #signature {
@@ -671,24 +1335,27 @@ impl Definition {
         }
     }
 
-    /// Generates a trait containing only methods with `&self` (or `&mut self`)
-    /// receiver.
-    fn generate_ref_trait(&self, mutable: bool) -> TokenStream {
-        let ref_trait = if mutable {
-            &self.ref_mut_trait_ident
-        } else {
-            &self.ref_trait_ident
-        };
+    /// Generates a trait containing only methods with the provided
+    /// [`RefReceiver`].
+    fn generate_ref_trait(&self, kind: RefReceiver) -> TokenStream {
+        let vis = &self.vis;
+        let ref_trait = self.ref_trait_ident_of(kind);
 
         let generics = self.ref_trait_generics();
         let where_clause = &generics.where_clause;
 
-        let methods = self.ref_trait_signatures(mutable);
+        let assoc_types = self.assoc_types_decls();
+        let self_args_types = self.self_args_types_decls();
+        let methods = self.ref_trait_signatures(kind);
 
+        // See `Self::generate_owned_trait()` for details.
         quote! {
             #[automatically_derived]
             #[allow(non_camel_case_types, reason = "macro expansion")]
-            trait #ref_trait #generics #where_clause {
+            #[doc(hidden)]
+            #vis trait #ref_trait #generics #where_clause {
+                #( #assoc_types )*
+                #( #self_args_types )*
                 #( #methods; )*
             }
         }
@@ -696,14 +1363,30 @@ impl Definition {
 
     /// Implements a trait generated by the [`Self::generate_ref_trait()`]
     /// method for an `Either`.
-    fn impl_ref_trait_for_either(&self, mutable: bool) -> TokenStream {
-        let mut_ = mutable.then(|| quote! { mut });
+    ///
+    /// If `last` is `true`, then implements it for an `Either` having a `Void`
+    /// on its right side (the last one in the `Either` chain).
+    ///
+    /// If `locked` is `true`, then implements it for an `Either` having a
+    /// `Locked` delegate on its left side, which is locked on each call.
+    /// Methods borrowing the receiver are unreachable for it, as the lock guard
+    /// is released on return.
+    fn impl_ref_trait_for_either(
+        &self,
+        kind: RefReceiver,
+        last: bool,
+        locked: bool,
+    ) -> TokenStream {
         let macro_path = &self.macro_path;
         let orig_trait = self.item.path();
-        let ref_trait = if mutable {
-            &self.ref_mut_trait_ident
+        let ref_trait = self.ref_trait_ident_of(kind);
+        let (left_ty, left) = if locked {
+            (
+                quote! { #macro_path::Locked<'__delegate, __Lock> },
+                quote! { &*<__Lock as #macro_path::Lock>::lock(__delegate.0) },
+            )
         } else {
-            &self.ref_trait_ident
+            (kind.wrap_ty(&quote! { __Left }), quote! { __delegate })
         };
 
         let (_, trait_ty_gens, _) = self.generics.split_for_impl();
@@ -711,73 +1394,143 @@ impl Definition {
         let ref_trait_generics = self.ref_trait_generics();
         let (_, ref_trait_ty_gens, _) = ref_trait_generics.split_for_impl();
 
+        let left_assoc = quote! { <__Left as #orig_trait #trait_ty_gens> };
+        let left_assoc_types = Self::left_assoc_types(&left_assoc);
+
         let impl_generics = {
             let mut gens = ref_trait_generics.clone();
 
-            let params: [syn::GenericParam; 2] =
-                [parse_quote! { __Left }, parse_quote! { __Right }];
-            gens.params.extend(params);
+            gens.params.push(parse_quote! { __Left });
+            gens.make_where_clause()
+                .predicates
+                .push(parse_quote! { __Left: #orig_trait #trait_ty_gens });
+
+            if locked {
+                gens.params.push(parse_quote! { __Lock });
+                let lock_predicates: [syn::WherePredicate; 2] = [
+                    parse_quote! { __Lock: #macro_path::Lock<Target = __Left> },
+                    parse_quote! { __Left: '__delegate },
+                ];
+                gens.make_where_clause().predicates.extend(lock_predicates);
+            }
 
-            let predicates: [syn::WherePredicate; 2] = [
-                parse_quote! { __Left: #orig_trait #trait_ty_gens },
-                parse_quote! { __Right: #ref_trait #ref_trait_ty_gens },
-            ];
-            gens.make_where_clause().predicates.extend(predicates);
+            if !last {
+                let right_args = ref_trait_generics.to_arguments_with(
+                    self.assoc_types_bindings_with(&left_assoc_types, false),
+                );
+
+                gens.params.push(parse_quote! { __Right });
+                gens.make_where_clause()
+                    .predicates
+                    .push(parse_quote! { __Right: #ref_trait #right_args });
+            }
 
             gens
         };
         let (impl_gens, _, where_clause) = impl_generics.split_for_impl();
 
-        let methods = self.ref_trait_signatures(mutable).map(|signature| {
+        let right_ty = if last {
+            quote! { #macro_path::Void }
+        } else {
+            quote! { __Right }
+        };
+
+        let right_assoc = quote! { <__Right as #ref_trait #ref_trait_ty_gens> };
+        let assoc_types = self.assoc_types_defs(
+            &left_assoc_types,
+            (!last).then_some(&right_assoc),
+        );
+        let self_args_types =
+            self.self_args_types_defs((!last).then_some(&right_assoc));
+
+        let methods = self.ref_trait_signatures(kind).map(|signature| {
             let (signature, method_name, method_inputs) =
                 signature.split_for_impl();
             let method_inputs = method_inputs.collect::<Vec<_>>();
 
-            quote! {
-                #signature {
-                    match self {
-                        Self::Left(__delegate) => {
-                            <__Left as #orig_trait #trait_ty_gens>
-                            ::#method_name(
-                                __delegate, #( #method_inputs ),*
-                            )
-                        }
-                        Self::Right(__delegate) => {
-                            <__Right as #ref_trait #ref_trait_ty_gens>
-                            ::#method_name(
-                                __delegate, #( #method_inputs ),*
-                            )
-                        }
-                    }
-                }
-            }
+            let left = if locked && self.borrows_receiver(signature) {
+                quote! { #macro_path::Locked::unreachable(__delegate) }
+            } else {
+                left.clone()
+            };
+            let left_call = self.wrap_either_variant(
+                signature,
+                true,
+                last,
+                quote! {
+                    <__Left as #orig_trait #trait_ty_gens>
+                    ::#method_name(#left, #( #method_inputs ),*)
+                },
+            );
+            let right_call = if last {
+                quote! { match __delegate {} }
+            } else {
+                self.wrap_either_variant(
+                    signature,
+                    false,
+                    last,
+                    quote! {
+                        <__Right as #ref_trait #ref_trait_ty_gens>
+                        ::#method_name(
+                            __delegate, #( #method_inputs ),*
+                        )
+                    },
+                )
+            };
+
+            self.either_method_impl(signature, &left_call, &right_call)
         });
 
         quote! {
             #[automatically_derived]
             impl #impl_gens #ref_trait #ref_trait_ty_gens
-             for #macro_path::Either<&'__delegate #mut_ __Left, __Right>
-                 #where_clause
+             for #macro_path::Either<#left_ty, #right_ty> #where_clause
             {
+                #( #assoc_types )*
+                #( #self_args_types )*
                 #( #methods )*
             }
         }
     }
 
     /// Implements a trait generated by the [`Self::generate_ref_trait()`]
-    /// method for a `Void`.
-    fn impl_ref_trait_for_void(&self, mutable: bool) -> TokenStream {
+    /// method for `&self` receivers for an `Either` having a `Locked` delegate
+    /// on its left side.
+    ///
+    /// Similarly to the [`Self::impl_ref_trait_for_void()`] method, the last
+    /// `Either` in the chain is implemented too, if the trait has associated
+    /// types or opaque methods.
+    fn impl_ref_trait_for_locked(&self) -> TokenStream {
+        let kind = RefReceiver::Shared;
+        let mut impls = self.impl_ref_trait_for_either(kind, false, true);
+        if !self.assoc_types.is_empty() || !self.opaque_methods.is_empty() {
+            impls.extend(self.impl_ref_trait_for_either(kind, true, true));
+        }
+        impls
+    }
+
+    /// Implements a trait generated by the [`Self::generate_ref_trait()`]
+    /// method for a `Void`.
+    ///
+    /// A `Void` cannot resolve associated types of the trait, nor return opaque
+    /// types, so, if there are any, the last `Either` in the chain is
+    /// implemented instead.
+    fn impl_ref_trait_for_void(&self, kind: RefReceiver) -> TokenStream {
+        if !self.assoc_types.is_empty() || !self.opaque_methods.is_empty() {
+            return self.impl_ref_trait_for_either(kind, true, false);
+        }
+
         let macro_path = &self.macro_path;
-        let ref_trait = if mutable {
-            &self.ref_mut_trait_ident
-        } else {
-            &self.ref_trait_ident
-        };
+        let ref_trait = self.ref_trait_ident_of(kind);
 
         let generics = self.ref_trait_generics();
         let (impl_gens, ty_gens, where_clause) = generics.split_for_impl();
 
-        let methods = self.ref_trait_signatures(mutable).map(|signature| {
+        let self_args_types = self.self_args().map(|arg| {
+            let ident = arg.assoc_ident();
+            quote! { type #ident = #macro_path::Void; }
+        });
+        let methods = self.ref_trait_signatures(kind).map(|signature| {
             quote! {
                 #signature {
                     match self {}
@@ -790,6 +1543,7 @@ impl Definition {
             impl #impl_gens #ref_trait #ty_gens
              for #macro_path::Void #where_clause
             {
+                #( #self_args_types )*
                 #( #methods )*
             }
         }
@@ -806,14 +1560,15 @@ impl Definition {
         let owned_ident = &self.owned_trait_ident;
         let ref_ident = &self.ref_trait_ident;
         let ref_mut_ident = &self.ref_mut_trait_ident;
-
         let for_ty = quote! { __Delegate };
         let wrapper_ty = &self.wrapper_ty;
 
         let (_, trait_ty_gens, _) = self.generics.split_for_impl();
 
-        let ref_trait_generics = self.ref_trait_generics();
-        let (_, ref_trait_ty_gens, _) = ref_trait_generics.split_for_impl();
+        let owned_assoc = quote! {
+            <<#for_ty as #macro_path::Convert>::Owned
+             as #owned_ident #trait_ty_gens>
+        };
 
         let ref_trait_anon_generics = {
             let mut gens = self.generics.clone();
@@ -823,72 +1578,39 @@ impl Definition {
         let (_, ref_trait_anon_ty_gens, _) =
             ref_trait_anon_generics.split_for_impl();
 
-        let impl_generics = {
-            let mut gens = self.generics.clone();
-
-            gens.params.push(parse_quote! { #for_ty });
-
-            let predicates: [syn::WherePredicate; 4] = [
-                parse_quote! { #for_ty: #macro_path::Convert },
-                parse_quote! {
-                    <#for_ty as #macro_path::Convert>::Owned:
-                        #owned_ident #trait_ty_gens
-                },
-                parse_quote! {
-                    for<'__delegate>
-                    <#for_ty as #macro_path::Convert>::Ref<'__delegate>:
-                        #ref_ident #ref_trait_ty_gens
-                },
-                parse_quote! {
-                    for<'__delegate>
-                    <#for_ty as #macro_path::Convert>::RefMut<'__delegate>:
-                        #ref_mut_ident #ref_trait_ty_gens
-                },
-            ];
-            gens.make_where_clause().predicates.extend(predicates);
-
-            gens
-        };
+        let impl_generics = self.blanket_impl_generics(&for_ty, &owned_assoc);
         let (impl_gens, _, where_clause) = impl_generics.split_for_impl();
 
-        let owned_methods = self.methods_owned.iter().map(|m| {
-            let (signature, method_name, method_inputs) =
-                m.sig.split_for_impl();
+        let assoc_types = self.wrapped_assoc_types_defs(&owned_assoc);
+        let consts = self.wrapped_consts_defs(&owned_assoc);
 
-            quote! {
-                #signature {
-                    <<#for_ty as #macro_path::Convert>::Owned
-                     as #owned_ident>
-                    ::#method_name(
-                        <#for_ty as #macro_path::Convert>::convert_owned(
-                            self.0
-                        ),
-                        #( #method_inputs ),*
-                    )
-                }
-            }
-        });
+        let owned_methods =
+            self.blanket_impl_owned_methods(&for_ty, &owned_assoc);
         let ref_methods = self.methods_ref.iter().map(|m| {
-            let (signature, method_name, method_inputs) =
-                m.sig.split_for_impl();
+            let (signature, method_name, _) = m.sig.split_for_impl();
+            let method_inputs = self.blanket_impl_inputs(signature, &for_ty);
 
-            quote! {
-                #signature {
+            self.blanket_impl_method(
+                signature,
+                &owned_assoc,
+                quote! {
                     <<#for_ty as #macro_path::Convert>::Ref<'_>
                      as #ref_ident #ref_trait_anon_ty_gens>
                     ::#method_name(
                         <#for_ty as #macro_path::Convert>::convert_ref(&self.0),
                         #( #method_inputs ),*
                     )
-                }
-            }
+                },
+            )
         });
         let ref_mut_methods = self.methods_ref_mut.iter().map(|m| {
-            let (signature, method_name, method_inputs) =
-                m.sig.split_for_impl();
+            let (signature, method_name, _) = m.sig.split_for_impl();
+            let method_inputs = self.blanket_impl_inputs(signature, &for_ty);
 
-            quote! {
-                #signature {
+            self.blanket_impl_method(
+                signature,
+                &owned_assoc,
+                quote! {
                     <<#for_ty as #macro_path::Convert>::RefMut<'_>
                      as #ref_mut_ident #ref_trait_anon_ty_gens>
                     ::#method_name(
@@ -897,9 +1619,15 @@ impl Definition {
                         ),
                         #( #method_inputs ),*
                     )
-                }
-            }
+                },
+            )
         });
+        let pin_mut_methods = self.blanket_impl_pin_mut_methods(
+            &for_ty,
+            &owned_assoc,
+            &ref_trait_anon_ty_gens,
+        );
+        let static_methods = self.blanket_impl_static_methods(&for_ty);
 
         quote! {
             #[automatically_derived]
@@ -907,9 +1635,494 @@ impl Definition {
                 for #wrapper_ty < #for_ty >
             #where_clause
             {
+                #( #assoc_types )*
+                #( #consts )*
                 #( #owned_methods )*
                 #( #ref_methods )*
                 #( #ref_mut_methods )*
+                #( #pin_mut_methods )*
+                #( #static_methods )*
+            }
+        }
+    }
+
+    /// Implements the methods with `self` receiver (or a typed pointer one) in
+    /// the [`Self::blanket_impl_for_wrapper_type()`] method, unwrapping the
+    /// pointer to the wrapper, if any.
+    ///
+    /// Shared pointers are unwrapped by moving their value out, if they're
+    /// unique, or by cloning the variant they point to otherwise.
+    fn blanket_impl_owned_methods<'a>(
+        &'a self,
+        for_ty: &'a TokenStream,
+        owned_assoc: &'a TokenStream,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        let macro_path = &self.macro_path;
+        let owned_ident = &self.owned_trait_ident;
+
+        self.methods_owned.iter().map(move |m| {
+            let (signature, method_name, _) = m.sig.split_for_impl();
+            let method_inputs = self.blanket_impl_inputs(signature, for_ty);
+
+            let owned = match m.sig.receiver().and_then(ReceiverPointer::parse)
+            {
+                None => quote! {
+                    <#for_ty as #macro_path::Convert>::convert_owned(self.0)
+                },
+                Some(ptr) if !ptr.shared => quote! {
+                    <#for_ty as #macro_path::Convert>::convert_owned((*self).0)
+                },
+                Some(ReceiverPointer { path, .. }) => quote! {
+                    match #path::try_unwrap(self) {
+                        ::core::result::Result::Ok(__this) => {
+                            <#for_ty as #macro_path::Convert>
+                                ::convert_owned(__this.0)
+                        }
+                        ::core::result::Result::Err(__this) => {
+                            #macro_path::CloneRef::clone_ref(
+                                <#for_ty as #macro_path::Convert>
+                                    ::convert_ref(&__this.0),
+                            )
+                        }
+                    }
+                },
+            };
+
+            self.blanket_impl_method(
+                signature,
+                owned_assoc,
+                quote! {
+                    <<#for_ty as #macro_path::Convert>::Owned
+                     as #owned_ident>
+                    ::#method_name(#owned, #( #method_inputs ),*)
+                },
+            )
+        })
+    }
+
+    /// Implements the methods with `self: Pin<&mut Self>` receiver in the
+    /// [`Self::blanket_impl_for_wrapper_type()`] method, projecting the pinned
+    /// wrapper onto its delegates.
+    fn blanket_impl_pin_mut_methods<'a>(
+        &'a self,
+        for_ty: &'a TokenStream,
+        owned_assoc: &'a TokenStream,
+        ref_trait_anon_ty_gens: &'a syn::TypeGenerics<'a>,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        let macro_path = &self.macro_path;
+        let pin_mut_ident = &self.pin_mut_trait_ident;
+
+        self.methods_pin_mut.iter().map(move |m| {
+            let (signature, method_name, method_inputs) =
+                m.sig.split_for_impl();
+
+            self.blanket_impl_method(
+                signature,
+                owned_assoc,
+                quote! {
+                    <<#for_ty as #macro_path::ConvertPin>::PinMut<'_>
+                     as #pin_mut_ident #ref_trait_anon_ty_gens>
+                    ::#method_name(
+                        <#for_ty as #macro_path::ConvertPin>::convert_pin_mut(
+                            // SAFETY: Wrapper is structurally pinned, as it
+                            //         neither implements `Drop`, nor is
+                            //         `Unpin`, unless the wrapped type is.
+                            #[allow(unsafe_code, reason = "macro expansion")]
+                            unsafe {
+                                ::core::pin::Pin::map_unchecked_mut(
+                                    self,
+                                    |__wrapper| &mut __wrapper.0,
+                                )
+                            }
+                        ),
+                        #( #method_inputs ),*
+                    )
+                },
+            )
+        })
+    }
+
+    /// Implements the associated functions without receiver in the
+    /// [`Self::blanket_impl_for_wrapper_type()`] method, delegating them to the
+    /// default delegate and converting the returned `Self` from it.
+    fn blanket_impl_static_methods<'a>(
+        &'a self,
+        for_ty: &'a TokenStream,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        let macro_path = &self.macro_path;
+        let trait_path = self.item.path();
+        let (_, trait_ty_gens, _) = self.generics.split_for_impl();
+
+        self.methods_static.iter().map(move |m| {
+            let (signature, method_name, method_inputs) =
+                m.sig.split_for_impl();
+
+            let default_ty = quote! {
+                <#for_ty as #macro_path::ConvertDefault>::Default
+            };
+            let call = quote! {
+                <#default_ty as #trait_path #trait_ty_gens>::#method_name(
+                    #( #method_inputs ),*
+                )
+            };
+            let body = if m.sig.returns_self() {
+                quote! {
+                    Self(
+                        <#for_ty as #macro_path::ConvertDefault>
+                            ::convert_default(#call)
+                    )
+                }
+            } else {
+                call
+            };
+
+            quote! {
+                #signature {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// Returns inputs of the provided method for the
+    /// [`Self::blanket_impl_for_wrapper_type()`] method, converting its `Self`
+    /// arguments (wrapping the provided type) into their `Either` chains.
+    fn blanket_impl_inputs(
+        &self,
+        sig: &syn::Signature,
+        for_ty: &TokenStream,
+    ) -> Vec<TokenStream> {
+        sig.inputs
+            .iter()
+            .filter_map(|i| match i {
+                syn::FnArg::Typed(t) => Some(t),
+                syn::FnArg::Receiver(_) => None,
+            })
+            .map(|t| {
+                let pat = &t.pat;
+                SelfArg::parse(&t.ty).map_or_else(
+                    || pat.to_token_stream(),
+                    |arg| arg.convert_expr(pat, for_ty, &self.macro_path),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns definitions of the associated types of the trait, resolving them
+    /// from the provided `qself` (like `<Type as Trait>`), and wrapping the sum
+    /// types.
+    fn wrapped_assoc_types_defs(
+        &self,
+        qself: &TokenStream,
+    ) -> impl Iterator<Item = TokenStream> {
+        self.assoc_types.iter().filter(|ty| ty.supertrait.is_none()).map(
+            move |ty| {
+                let ident = &ty.item.ident;
+                let assoc_ty = quote! { #qself::#ident };
+                let assoc_ty = ty.sum.as_ref().map_or_else(
+                    || assoc_ty.clone(),
+                    |sum| sum.wrap_ty(&assoc_ty, &self.macro_path),
+                );
+
+                quote! { type #ident = #assoc_ty; }
+            },
+        )
+    }
+
+    /// Returns definitions of the associated constants of the trait, resolving
+    /// them from the provided `qself` (like `<Type as Trait>`).
+    fn wrapped_consts_defs(
+        &self,
+        qself: &TokenStream,
+    ) -> impl Iterator<Item = TokenStream> {
+        self.consts.iter().map(move |c| {
+            let ident = &c.item.ident;
+            let ty = &c.item.ty;
+
+            quote! { const #ident: #ty = #qself::#ident; }
+        })
+    }
+
+    /// Returns [`Generics`] for the [`Self::blanket_impl_for_wrapper_type()`]
+    /// method, bounding the provided type's `Convert` associated types with the
+    /// generated traits.
+    fn blanket_impl_generics(
+        &self,
+        for_ty: &TokenStream,
+        owned_assoc: &TokenStream,
+    ) -> syn::Generics {
+        let macro_path = &self.macro_path;
+        let owned_ident = &self.owned_trait_ident;
+        let ref_ident = &self.ref_trait_ident;
+        let ref_mut_ident = &self.ref_mut_trait_ident;
+
+        let (_, trait_ty_gens, _) = self.generics.split_for_impl();
+        let self_args_bindings = self.self_args().map(|arg| {
+            let ident = arg.assoc_ident();
+            let ty = match arg {
+                SelfArg::Owned => quote! { Owned },
+                SelfArg::Ref => quote! { Ref<'__delegate> },
+                SelfArg::RefMut => quote! { RefMut<'__delegate> },
+            };
+            parse_quote! { #ident = <#for_ty as #macro_path::Convert>::#ty }
+        });
+        let ref_trait_args = self.ref_trait_generics().to_arguments_with(
+            self.assoc_types_bindings(owned_assoc, true)
+                .into_iter()
+                .chain(self_args_bindings),
+        );
+
+        let mut gens = self.generics.clone();
+
+        gens.params.push(parse_quote! { #for_ty });
+
+        let unlocked = self.unlocked_bound();
+        let predicates: [syn::WherePredicate; 2] = [
+            parse_quote! { #for_ty: #macro_path::Convert },
+            parse_quote! {
+                for<'__delegate>
+                <#for_ty as #macro_path::Convert>::Ref<'__delegate>:
+                    #ref_ident #ref_trait_args #unlocked
+            },
+        ];
+        gens.make_where_clause().predicates.extend(predicates);
+
+        // Owned and mutably referenced delegates are required only if there are
+        // items to delegate to them, as not every type supports them (like
+        // shared pointers delegated through).
+        if self.requires_owned() {
+            gens.make_where_clause().predicates.push(parse_quote! {
+                <#for_ty as #macro_path::Convert>::Owned:
+                    #owned_ident #trait_ty_gens
+            });
+        }
+        if self.requires_ref_mut() {
+            gens.make_where_clause().predicates.push(parse_quote! {
+                for<'__delegate>
+                <#for_ty as #macro_path::Convert>::RefMut<'__delegate>:
+                    #ref_mut_ident #ref_trait_args
+            });
+        }
+
+        // Pinned delegates are required only if there are methods to delegate
+        // to them, as not every type supports pin projection.
+        if !self.methods_pin_mut.is_empty() {
+            let pin_mut_ident = &self.pin_mut_trait_ident;
+            let pin_predicates: [syn::WherePredicate; 2] = [
+                parse_quote! { #for_ty: #macro_path::ConvertPin },
+                parse_quote! {
+                    for<'__delegate>
+                    <#for_ty as #macro_path::ConvertPin>::PinMut<'__delegate>:
+                        #pin_mut_ident #ref_trait_args
+                },
+            ];
+            gens.make_where_clause().predicates.extend(pin_predicates);
+        }
+
+        // Variants are cloned only if there are methods with shared pointer
+        // receivers, as not every type supports it.
+        let has_shared_receivers = self.methods_owned.iter().any(|m| {
+            m.sig
+                .receiver()
+                .and_then(ReceiverPointer::parse)
+                .is_some_and(|ptr| ptr.shared)
+        });
+        if has_shared_receivers {
+            gens.make_where_clause().predicates.push(parse_quote! {
+                for<'__delegate>
+                <#for_ty as #macro_path::Convert>::Ref<'__delegate>:
+                    #macro_path::CloneRef<
+                        Owned = <#for_ty as #macro_path::Convert>::Owned,
+                    >
+            });
+        }
+
+        // Delegates are converted back only if there are methods returning
+        // `Self`, as not every type supports it.
+        if !self.self_returning_methods.is_empty() {
+            gens.make_where_clause()
+                .predicates
+                .push(parse_quote! { #for_ty: #macro_path::ConvertBack });
+        }
+
+        gens.make_where_clause()
+            .predicates
+            .extend(self.default_predicates(for_ty, owned_assoc));
+
+        gens.make_where_clause()
+            .predicates
+            .extend(self.assoc_types_predicates(for_ty, owned_assoc));
+
+        gens
+    }
+
+    /// Returns predicates for the [`Self::blanket_impl_generics()`] method on
+    /// the associated types resolved from the provided `owned_assoc`:
+    /// - bounds of the sum types, satisfied by their wrappers;
+    /// - equality to the associated types of supertraits, as they cannot be
+    ///   defined in the impl.
+    fn assoc_types_predicates(
+        &self,
+        for_ty: &TokenStream,
+        owned_assoc: &TokenStream,
+    ) -> Vec<syn::WherePredicate> {
+        let macro_path = &self.macro_path;
+        let owned_ident = &self.owned_trait_ident;
+        let (_, trait_ty_gens, _) = self.generics.split_for_impl();
+
+        let assoc_types_names = self.assoc_types_names();
+        let owned_ty: syn::Type =
+            parse_quote! { <#for_ty as #macro_path::Convert>::Owned };
+        let owned_trait: syn::Path =
+            parse_quote! { #owned_ident #trait_ty_gens };
+        let sum_predicates = self.assoc_types.iter().filter_map(|ty| {
+            let sum = ty.sum.as_ref()?;
+            if ty.item.bounds.is_empty() {
+                return None;
+            }
+
+            let ident = &ty.item.ident;
+            let wrapped =
+                sum.wrap_ty(&quote! { #owned_assoc::#ident }, macro_path);
+            let bounds = &ty.item.bounds;
+
+            let mut pred: syn::WherePredicate =
+                parse_quote! { #wrapped: #bounds };
+            pred.qualify_self_assoc_types(
+                &assoc_types_names,
+                &owned_ty,
+                &owned_trait,
+            );
+            Some(pred)
+        });
+        let supertrait_predicates = self.assoc_types.iter().filter_map(|ty| {
+            let supertrait = ty.supertrait.as_ref()?;
+            let ident = &ty.item.ident;
+            Some(parse_quote! {
+                Self: #supertrait<#ident = #owned_assoc::#ident>
+            })
+        });
+
+        sum_predicates.chain(supertrait_predicates).collect()
+    }
+
+    /// Indicates whether owned delegates are required by the
+    /// [`Self::blanket_impl_generics()`] method, as there are items to
+    /// delegate to them.
+    fn requires_owned(&self) -> bool {
+        self.self_args().next().is_some()
+            || !self.methods_owned.is_empty()
+            || !self.methods_static.is_empty()
+            || !self.assoc_types.is_empty()
+            || !self.consts.is_empty()
+    }
+
+    /// Indicates whether mutably referenced delegates are required by the
+    /// [`Self::blanket_impl_generics()`] method, as there are methods to
+    /// delegate to them.
+    fn requires_ref_mut(&self) -> bool {
+        self.self_args().next().is_some() || !self.methods_ref_mut.is_empty()
+    }
+
+    /// Indicates whether the provided [lifted] method [`Signature`] borrows its
+    /// receiver for a named lifetime, by the returned value or by its `Self`
+    /// arguments, so it cannot be delegated to a `Locked` delegate.
+    ///
+    /// [lifted]: util::SignatureExt::lift_receiver_lifetime()
+    /// [`Signature`]: syn::Signature
+    fn borrows_receiver(&self, sig: &syn::Signature) -> bool {
+        let has_named_receiver = self
+            .methods_ref
+            .iter()
+            .find(|m| m.sig.ident == sig.ident)
+            .and_then(|m| m.sig.receiver()?.lifetime())
+            .is_some_and(|lt| lt.ident != "_");
+
+        has_named_receiver
+            || self.opaque_methods.contains(&sig.ident)
+            || self.binary_methods.contains(&sig.ident)
+            || sig.output_contains_lifetime(&parse_quote! { '__delegate })
+    }
+
+    /// Returns predicates for the [`Self::blanket_impl_generics()`] method,
+    /// bounding the default delegate of the provided type with the trait.
+    ///
+    /// Default delegates are required only if there are associated functions
+    /// to delegate to them, as not every type has one.
+    fn default_predicates(
+        &self,
+        for_ty: &TokenStream,
+        owned_assoc: &TokenStream,
+    ) -> Vec<syn::WherePredicate> {
+        if self.methods_static.is_empty() {
+            return vec![];
+        }
+
+        let macro_path = &self.macro_path;
+        let trait_path = self.item.path();
+        let trait_args = self
+            .generics
+            .to_arguments_with(self.assoc_types_bindings(owned_assoc, false));
+
+        vec![
+            parse_quote! { #for_ty: #macro_path::ConvertDefault },
+            parse_quote! {
+                <#for_ty as #macro_path::ConvertDefault>::Default:
+                    #trait_path #trait_args
+            },
+        ]
+    }
+
+    /// Returns an additional `Unlocked` bound of the shared references to the
+    /// delegates for the [`Self::blanket_impl_generics()`] method, if any.
+    ///
+    /// Locked delegates are released on return, so they're required to not be
+    /// such only if there are methods borrowing them by the returned values.
+    fn unlocked_bound(&self) -> Option<TokenStream> {
+        let macro_path = &self.macro_path;
+
+        self.ref_trait_signatures(RefReceiver::Shared)
+            .any(|sig| self.borrows_receiver(&sig))
+            .then(|| quote! { + #macro_path::Unlocked })
+    }
+
+    /// Implements the provided method in the
+    /// [`Self::blanket_impl_for_wrapper_type()`] method with the provided
+    /// `call`, wrapping its result into a wrapper of the sum type, if the
+    /// method returns one, or converting it back into the wrapped type, if the
+    /// method returns `Self`.
+    ///
+    /// The sum type is resolved from the provided `qself` (like
+    /// `<Type as Trait>`).
+    fn blanket_impl_method(
+        &self,
+        sig: &syn::Signature,
+        qself: &TokenStream,
+        call: TokenStream,
+    ) -> TokenStream {
+        let sum = sig
+            .returns_self_assoc_type(&self.sum_types_names())
+            .and_then(|ident| {
+                let ty = self
+                    .assoc_types
+                    .iter()
+                    .find(|ty| &ty.item.ident == ident)?;
+                Some((ident, ty.sum.as_ref()?))
+            });
+        let macro_path = &self.macro_path;
+        let body = match sum {
+            Some((ident, sum)) => {
+                sum.wrap_expr(&call, &quote! { #qself::#ident }, macro_path)
+            }
+            None if self.self_returning_methods.contains(&sig.ident) => {
+                quote! { Self(#macro_path::ConvertBack::convert_back(#call)) }
+            }
+            None => call,
+        };
+
+        quote! {
+            #sig {
+                #body
             }
         }
     }
@@ -942,59 +2155,70 @@ impl Definition {
             ),
         };
 
-        let mut seq_num: usize = 0;
-        let methods = self
-            .methods_owned
-            .iter()
-            .chain(&self.methods_ref)
-            .chain(&self.methods_ref_mut)
-            .map(|m| {
-                let (_, method_name, method_inputs) = m.sig.split_for_impl();
-
-                let signature = self.bind_signature_types(&m.sig, &mut seq_num);
+        let assoc_qself = quote! { <#self_wrapped as #trait_path #ty_gens> };
+        let assoc_items = self.template_assoc_items(&assoc_qself);
 
-                // TODO: Use `RefCast` here instead of `mem::transmute`.
-                let mut receiver = quote! { ::core::mem::transmute(self) };
-                if m.sig.unsafety.is_none() {
-                    receiver = quote! {
+        let mut seq_num: usize = 0;
+        let methods = self.methods().map(|m| {
+            let method_name = &m.sig.ident;
+
+            let signature = self.bind_signature_types(&m.sig, &mut seq_num);
+
+            // Arguments of `unsafe` methods are transmuted inside the
+            // `unsafe` body.
+            let safe = m.sig.unsafety.is_none();
+            let inputs = m.sig.inputs.iter().map(|i| match i {
+                syn::FnArg::Receiver(r) => {
+                    let owned =
+                        r.reference.is_none() && r.colon_token.is_none();
+                    Self::transmute_to_wrapper(&quote! { self }, owned, safe)
+                }
+                syn::FnArg::Typed(t) => SelfArg::parse(&t.ty).map_or_else(
+                    || t.pat.to_token_stream(),
+                    |arg| {
+                        let owned = arg == SelfArg::Owned;
+                        Self::transmute_to_wrapper(&t.pat, owned, safe)
+                    },
+                ),
+            });
+            let mismatch_check =
+                self.template_mismatch_check(&m.sig, &trait_path);
+            let mut call = quote! {
+                <#self_wrapped as #trait_path #ty_gens>:: #method_name(
+                    #( #inputs ),*
+                )
+            };
+            if m.sig.returns_self() {
+                call = quote! { #call.0 };
+            }
+            let body = quote! {
+                #mismatch_check
+                #call
+            };
+            if m.sig.unsafety.is_some() {
+                quote! {
+                    #signature {
                         // SAFETY: Wrapper is `#[repr(transparent)]`.
                         #[allow( // macro expansion
                             clippy::missing_transmute_annotations,
                             clippy::transmute_ptr_to_ptr,
-                            unsafe_code,
                             reason = "macro expansion",
                         )]
-                        unsafe { #receiver }
-                    };
-                }
-                let body = quote! {
-                    <#self_wrapped as #trait_path #ty_gens>:: #method_name(
-                        #receiver, #( #method_inputs ),*
-                    )
-                };
-                if m.sig.unsafety.is_some() {
-                    quote! {
-                        #signature {
-                            // SAFETY: Wrapper is `#[repr(transparent)]`.
-                            #[allow( // macro expansion
-                                clippy::missing_transmute_annotations,
-                                clippy::transmute_ptr_to_ptr,
-                                reason = "macro expansion",
-                            )]
-                            unsafe { #body }
-                        }
-                    }
-                } else {
-                    quote! {
-                        #signature { #body }
+                        unsafe { #body }
                     }
                 }
-            });
+            } else {
+                quote! {
+                    #signature { #body }
+                }
+            }
+        });
 
         let impl_block = quote! {
             #[automatically_derived]
             #unsafety impl #impl_gens #trait_path #ty_gens for T #where_clause
             {
+                #( #assoc_items )*
                 #( #methods )*
             }
         };
@@ -1029,6 +2253,143 @@ impl Definition {
         }
     }
 
+    /// Transmutes the provided `expr` into a wrapper of its type, for the
+    /// template of the [`Self::impl_macro_for_delegated_trait()`] method.
+    ///
+    /// If not `safe`, then the `expr` is expected to be inside an `unsafe`
+    /// block already.
+    ///
+    /// `owned` values are transmuted with `mem::transmute_copy()`, as
+    /// `mem::transmute()` cannot prove the sizes of generic types being equal
+    /// when the wrapped type is a proxy of the `Self` type (see the `derive`
+    /// attribute argument on struct fields), so the sizes are asserted at
+    /// compile time instead.
+    fn transmute_to_wrapper(
+        expr: &dyn ToTokens,
+        owned: bool,
+        safe: bool,
+    ) -> TokenStream {
+        if owned {
+            return quote! {
+                {
+                    fn __transmute<T, W>(value: T) -> W {
+                        const {
+                            ::core::assert!(
+                                ::core::mem::size_of::<T>()
+                                    == ::core::mem::size_of::<W>(),
+                            );
+                        }
+                        // SAFETY: Wrapper is `#[repr(transparent)]`, and the
+                        //         sizes are asserted above.
+                        #[allow(unsafe_code, reason = "macro expansion")]
+                        unsafe {
+                            ::core::mem::transmute_copy(
+                                &::core::mem::ManuallyDrop::new(value),
+                            )
+                        }
+                    }
+                    __transmute(#expr)
+                }
+            };
+        }
+
+        // TODO: Use `RefCast` here instead of `mem::transmute`.
+        let expr = quote! { ::core::mem::transmute(#expr) };
+        if !safe {
+            return expr;
+        }
+        quote! {
+            // SAFETY: Wrapper is `#[repr(transparent)]`.
+            #[allow( // macro expansion
+                clippy::missing_transmute_annotations,
+                clippy::transmute_ptr_to_ptr,
+                unsafe_code,
+                reason = "macro expansion",
+            )]
+            unsafe { #expr }
+        }
+    }
+
+    /// Returns a check of the provided method's `Self` arguments holding the
+    /// same variant as its receiver for the template of the
+    /// [`Self::impl_macro_for_delegated_trait()`] method, returning the result
+    /// of the method's fallback if they don't.
+    fn template_mismatch_check(
+        &self,
+        sig: &syn::Signature,
+        trait_path: &syn::Path,
+    ) -> Option<TokenStream> {
+        let macro_path = &self.macro_path;
+        let (_, ty_gens, _) = self.generics.split_for_impl();
+
+        let self_args = SelfArg::all_of(sig);
+        if self_args.is_empty() {
+            return None;
+        }
+
+        let (_, method_name, method_inputs) = sig.split_for_impl();
+        let mismatch_fn = format_ident!("__delegate_mismatch_{method_name}");
+
+        let this = if sig.receiver().is_some_and(|r| r.reference.is_none()) {
+            quote! { &self }
+        } else {
+            quote! { &*self }
+        };
+        let checks = self_args.iter().map(|(pat, arg)| {
+            let other = if *arg == SelfArg::Owned {
+                quote! { &#pat }
+            } else {
+                quote! { &*#pat }
+            };
+            quote! {
+                #macro_path::SameVariant::same_variant(
+                    &#macro_path::Convert::convert_ref(#this),
+                    &#macro_path::Convert::convert_ref(#other),
+                )
+            }
+        });
+
+        Some(quote! {
+            if #( !#checks )||* {
+                return <Self as #trait_path #ty_gens>
+                    ::#mismatch_fn(self, #( #method_inputs ),*);
+            }
+        })
+    }
+
+    /// Returns definitions of the associated types and constants of the trait
+    /// for the template of the [`Self::impl_macro_for_delegated_trait()`]
+    /// method, resolving them from the provided `qself` (like
+    /// `<Type as Trait>`).
+    fn template_assoc_items(
+        &self,
+        qself: &TokenStream,
+    ) -> impl Iterator<Item = TokenStream> {
+        let assoc_types =
+            self.assoc_types.iter().filter(|ty| ty.supertrait.is_none()).map(
+                move |ty| {
+                    let ident = &ty.item.ident;
+                    // Marks sum types for `impl_for!`, so their agreement is
+                    // not asserted.
+                    let sum_attr =
+                        ty.sum.is_some().then(|| quote! { #[delegate(sum)] });
+
+                    quote! {
+                        #sum_attr
+                        type #ident = #qself::#ident;
+                    }
+                },
+            );
+        let consts = self.consts.iter().map(move |c| {
+            let ident = &c.item.ident;
+            let ty = &c.item.ty;
+
+            quote! { const #ident: #ty = #qself::#ident; }
+        });
+
+        assoc_types.chain(consts)
+    }
+
     /// Implements the delegated trait for provided `for` types.
     fn impl_trait_for(&self) -> TokenStream {
         let macro_path = &self.macro_path;
@@ -1042,74 +2403,367 @@ impl Definition {
             Item::External(_) => quote! { #ident },
         };
 
-        self.delegate_for
-            .iter()
-            .map(|for_ty| {
-                let ty = &for_ty.ty;
-                let gens = self
-                    .generics
-                    .merge(for_ty.generics.as_ref())
-                    .merge_where_clause(for_ty.where_clause.as_ref());
-                let (impl_gens, _, where_clause) = gens.split_for_impl();
+        self.delegate_for
+            .iter()
+            .map(|for_ty| {
+                let ty = &for_ty.ty;
+                let gens = self
+                    .generics
+                    .merge(for_ty.generics.as_ref())
+                    .merge_where_clause(for_ty.where_clause.as_ref());
+                let (impl_gens, _, where_clause) = gens.split_for_impl();
+
+                quote! {
+                    #ident!(
+                        impl #impl_gens #trait_path #ty_gens as #wrapper
+                        for #ty
+                        #where_clause
+                    );
+                }
+            })
+            .collect()
+    }
+
+    /// Returns methods of the trait generated by the
+    /// [`Self::generate_owned_trait()`] method, along with [`ReceiverPointer`]s
+    /// of their original receivers.
+    ///
+    /// Typed receivers are replaced with the untyped `self` ones, as variants
+    /// are wrapped into [`ReceiverPointer`]s only when being delegated to.
+    /// Default implementations are omitted, as they're never called on the
+    /// generated trait.
+    fn owned_trait_methods(
+        &self,
+    ) -> impl Iterator<Item = (syn::TraitItemFn, Option<ReceiverPointer>)> {
+        self.methods_owned.iter().map(|m| {
+            let mut m = m.clone();
+            m.default = None;
+            m.semi_token = Some(token::Semi::default());
+            let pointer = m.sig.receiver().and_then(ReceiverPointer::parse);
+            if pointer.is_some() {
+                if let Some(syn::FnArg::Receiver(r)) = m.sig.inputs.first_mut()
+                {
+                    *r = parse_quote! { self };
+                }
+            }
+            (m, pointer)
+        })
+    }
+
+    /// Returns names of the associated types of the trait.
+    fn assoc_types_names(&self) -> HashSet<syn::Ident> {
+        self.assoc_types.iter().map(|ty| ty.item.ident.clone()).collect()
+    }
+
+    /// Returns names of the associated types of the trait marked with
+    /// `#[delegate(sum)]`.
+    fn sum_types_names(&self) -> HashSet<syn::Ident> {
+        self.assoc_types
+            .iter()
+            .filter(|ty| ty.sum.is_some())
+            .map(|ty| ty.item.ident.clone())
+            .collect()
+    }
+
+    /// Returns declarations of the associated types of the trait, suitable for
+    /// the generated traits.
+    ///
+    /// Sum types are declared without bounds, because an `Either` chain cannot
+    /// satisfy them without being wrapped.
+    fn assoc_types_decls(&self) -> impl Iterator<Item = TokenStream> {
+        self.assoc_types.iter().map(|ty| {
+            let ident = &ty.item.ident;
+            if ty.sum.is_some() {
+                return quote! { type #ident; };
+            }
+
+            let colon = &ty.item.colon_token;
+            let bounds = &ty.item.bounds;
+            let where_clause = &ty.item.generics.where_clause;
+
+            quote! { type #ident #colon #bounds #where_clause; }
+        })
+    }
+
+    /// Returns definitions of the associated types of the trait, resolving them
+    /// with the provided `left` function (like `<Type as Trait>::Assoc`).
+    ///
+    /// Sum types are defined as an `Either` of the ones resolved with the
+    /// provided `left` function and from the provided `right`, or a `Void` if
+    /// there is no `right`.
+    fn assoc_types_defs<'s>(
+        &'s self,
+        left: impl Fn(&AssocType) -> TokenStream + 's,
+        right: Option<&'s TokenStream>,
+    ) -> impl Iterator<Item = TokenStream> {
+        let macro_path = &self.macro_path;
+
+        self.assoc_types.iter().map(move |ty| {
+            let ident = &ty.item.ident;
+            let left = left(ty);
+            if ty.sum.is_none() {
+                return quote! { type #ident = #left; };
+            }
+
+            let right_ty = right.map_or_else(
+                || quote! { #macro_path::Void },
+                |right| quote! { #right::#ident },
+            );
+
+            quote! {
+                type #ident = #macro_path::Either<#left, #right_ty>;
+            }
+        })
+    }
+
+    /// Returns definitions of the associated constants of the trait, resolving
+    /// them from the provided `left` (like `<Type as Trait>`).
+    ///
+    /// Their values are asserted to be the same as the ones resolved from the
+    /// provided `right`, if any.
+    fn consts_defs(
+        &self,
+        left: &TokenStream,
+        right: Option<&TokenStream>,
+    ) -> impl Iterator<Item = TokenStream> {
+        self.consts.iter().map(move |c| {
+            let ident = &c.item.ident;
+            let ty = &c.item.ty;
+            let Some(right) = right else {
+                return quote! { const #ident: #ty = #left::#ident; };
+            };
+
+            let eq = ty.const_eq(
+                &quote! { #left::#ident },
+                &quote! { #right::#ident },
+                &self.macro_path,
+            );
+            let msg = format!(
+                "delegates have different values of `{ident}` associated \
+                 constant",
+            );
+
+            quote! {
+                const #ident: #ty = {
+                    ::core::assert!(#eq, #msg);
+                    #left::#ident
+                };
+            }
+        })
+    }
+
+    /// Returns bindings of the associated types of the trait to the ones
+    /// resolved from the provided `qself` (like `<Type as Trait>`).
+    ///
+    /// Sum types are bound only if `with_sums` is `true`.
+    fn assoc_types_bindings(
+        &self,
+        qself: &TokenStream,
+        with_sums: bool,
+    ) -> Vec<syn::GenericArgument> {
+        self.assoc_types_bindings_with(
+            |ty| {
+                let ident = &ty.item.ident;
+                quote! { #qself::#ident }
+            },
+            with_sums,
+        )
+    }
+
+    /// Returns bindings of the associated types of the trait to the ones
+    /// resolved with the provided `resolve` function (like
+    /// `<Type as Trait>::Assoc`).
+    ///
+    /// Sum types are bound only if `with_sums` is `true`.
+    fn assoc_types_bindings_with(
+        &self,
+        resolve: impl Fn(&AssocType) -> TokenStream,
+        with_sums: bool,
+    ) -> Vec<syn::GenericArgument> {
+        self.assoc_types
+            .iter()
+            .filter(|ty| with_sums || ty.sum.is_none())
+            .map(|ty| {
+                let ident = &ty.item.ident;
+                let resolved = resolve(ty);
+
+                parse_quote! { #ident = #resolved }
+            })
+            .collect()
+    }
+
+    /// Returns a function resolving the associated types of the `__Left`
+    /// delegate from the provided `left_assoc` (like `<__Left as Trait>`), or
+    /// from the supertraits they belong to, if any.
+    fn left_assoc_types(
+        left_assoc: &TokenStream,
+    ) -> impl Fn(&AssocType) -> TokenStream + '_ {
+        move |ty| {
+            let ident = &ty.item.ident;
+            ty.supertrait.as_ref().map_or_else(
+                || quote! { #left_assoc::#ident },
+                |supertrait| quote! { <__Left as #supertrait>::#ident },
+            )
+        }
+    }
+
+    /// Wraps the provided `expr`, being the result of the provided method,
+    /// into the `left` or right `Either` variant, if the method returns a sum
+    /// type or `Self`.
+    ///
+    /// Opaque sum types are wrapped only if the `Either` is not the `last` one
+    /// in the chain, as its right side is never reached.
+    fn wrap_either_variant(
+        &self,
+        sig: &syn::Signature,
+        left: bool,
+        last: bool,
+        expr: TokenStream,
+    ) -> TokenStream {
+        let macro_path = &self.macro_path;
+        let variant = if left {
+            quote! { Left }
+        } else {
+            quote! { Right }
+        };
+
+        if self.opaque_methods.contains(&sig.ident) {
+            let opaque_sum = &self.opaque_sum_ident;
+            return if last {
+                expr
+            } else {
+                quote! { #opaque_sum::#variant(#expr) }
+            };
+        }
+
+        if sig.returns_self_assoc_type(&self.sum_types_names()).is_some()
+            || self.self_returning_methods.contains(&sig.ident)
+        {
+            quote! { #macro_path::Either::#variant(#expr) }
+        } else {
+            expr
+        }
+    }
+
+    /// Implements the provided method for an `Either` with the provided calls
+    /// of its `left` and `right` delegates.
+    ///
+    /// `Self` arguments of the method are matched to hold the same variant as
+    /// the receiver, which is checked before delegating to the `Either`.
+    fn either_method_impl(
+        &self,
+        sig: &syn::Signature,
+        left_call: &TokenStream,
+        right_call: &TokenStream,
+    ) -> TokenStream {
+        let macro_path = &self.macro_path;
+
+        // `Self` arguments are looked up in the original method, as the
+        // provided signature may have their types replaced already.
+        let orig_sig = self
+            .methods_owned
+            .iter()
+            .chain(&self.methods_ref)
+            .chain(&self.methods_ref_mut)
+            .map(|m| &m.sig)
+            .find(|s| s.ident == sig.ident)
+            .filter(|s| self.binary_methods.contains(&s.ident));
+        let Some(orig_sig) = orig_sig else {
+            return quote! {
+                #sig {
+                    match self {
+                        Self::Left(__delegate) => {
+                            #left_call
+                        }
+                        Self::Right(__delegate) => {
+                            #right_call
+                        }
+                    }
+                }
+            };
+        };
+
+        let args = SelfArg::all_of(orig_sig)
+            .into_iter()
+            .map(|(pat, _)| pat)
+            .collect::<Vec<_>>();
 
-                quote! {
-                    #ident!(
-                        impl #impl_gens #trait_path #ty_gens as #wrapper
-                        for #ty
-                        #where_clause
-                    );
+        quote! {
+            #sig {
+                match (self, #( #args ),*) {
+                    (
+                        Self::Left(__delegate),
+                        #( #macro_path::Either::Left(#args) ),*
+                    ) => {
+                        #left_call
+                    }
+                    (
+                        Self::Right(__delegate),
+                        #( #macro_path::Either::Right(#args) ),*
+                    ) => {
+                        #right_call
+                    }
+                    _ => ::core::unreachable!(
+                        "variants are checked to be the same before delegating",
+                    ),
                 }
-            })
-            .collect()
-    }
-
-    // TODO: Add proper support for `Self:` bounds.
-    /// Generates assertion of `Self:` bounds containing only marker traits like
-    /// [`Sized`], [`Send`] or [`Sync`].
-    fn generate_self_bound_assertions(&self) -> TokenStream {
-        /// Returns an [`Iterator`] of [`syn::TraitBound`] for `Self:`.
-        fn self_trait_bounds(
-            pred: &syn::WherePredicate,
-        ) -> impl Iterator<Item = &syn::TraitBound> {
-            let self_: syn::Type = parse_quote! { Self };
-
-            match pred {
-                syn::WherePredicate::Type(syn::PredicateType {
-                    bounded_ty,
-                    bounds,
-                    ..
-                }) => (bounded_ty == &self_).then(|| {
-                    bounds.iter().filter_map(|b| match b {
-                        syn::TypeParamBound::Trait(tr) => Some(tr),
-                        syn::TypeParamBound::Lifetime(_)
-                        | syn::TypeParamBound::Verbatim(_)
-                        | syn::TypeParamBound::PreciseCapture(_) => None,
-                        bound => panic!("{bound:#?} not covered"),
-                    })
-                }),
-                syn::WherePredicate::Lifetime(_) => None,
-                pred => panic!("unknown `syn::WherePredicate`: {pred:?}"),
             }
-            .into_iter()
-            .flatten()
         }
+    }
 
-        let self_bounds = self
-            .generics
-            .where_clause
-            .iter()
-            .flat_map(|cl| cl.predicates.iter())
-            .flat_map(self_trait_bounds);
+    /// Returns all the [`SelfArg`] kinds, if there are methods with `Self`
+    /// arguments or returning `Self`, or nothing otherwise.
+    ///
+    /// The traits generated by the [`Self::generate_ref_trait()`] method
+    /// declare an associated type for each of them, representing an `Either`
+    /// chain of the `Self` arguments (or the returned `Self`).
+    fn self_args(&self) -> impl Iterator<Item = SelfArg> {
+        let kinds = if self.binary_methods.is_empty()
+            && self.self_returning_methods.is_empty()
+        {
+            [].as_slice()
+        } else {
+            [SelfArg::Owned, SelfArg::Ref, SelfArg::RefMut].as_slice()
+        };
+        kinds.iter().copied()
+    }
 
-        quote! {
-            #[automatically_derived]
-            const _: fn() = || {
-                struct OnlyMarkerSelfBoundsSupportedForNow;
+    /// Returns declarations of the associated types representing `Either`
+    /// chains of the `Self` arguments, suitable for the traits generated by
+    /// the [`Self::generate_ref_trait()`] method.
+    fn self_args_types_decls(&self) -> impl Iterator<Item = TokenStream> {
+        self.self_args().map(|arg| {
+            let ident = arg.assoc_ident();
+            quote! { type #ident; }
+        })
+    }
 
-                fn assert_impl_all<T: Sized #(+ #self_bounds )*>() {}
-                assert_impl_all::<OnlyMarkerSelfBoundsSupportedForNow>();
+    /// Returns definitions of the associated types declared by the
+    /// [`Self::self_args_types_decls()`] method, as an `Either` of the `__Left`
+    /// type and the ones resolved from the provided `right` (like
+    /// `<Type as Trait>`), or a `Void` if there is no `right`.
+    fn self_args_types_defs<'a>(
+        &'a self,
+        right: Option<&'a TokenStream>,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        let macro_path = &self.macro_path;
+
+        self.self_args().map(move |arg| {
+            let ident = arg.assoc_ident();
+            let left = match arg {
+                SelfArg::Owned => quote! { __Left },
+                SelfArg::Ref => quote! { &'__delegate __Left },
+                SelfArg::RefMut => quote! { &'__delegate mut __Left },
             };
-        }
+            let right_ty = right.map_or_else(
+                || quote! { #macro_path::Void },
+                |right| quote! { #right::#ident },
+            );
+
+            quote! {
+                type #ident = #macro_path::Either<#left, #right_ty>;
+            }
+        })
     }
 
     /// Returns an [`Iterator`] over methods with `&self` or `&mut self`
@@ -1118,24 +2772,62 @@ impl Definition {
     /// [lifted]: util::SignatureExt::lift_receiver_lifetime()
     fn ref_trait_signatures(
         &self,
-        mutable: bool,
+        kind: RefReceiver,
     ) -> impl Iterator<Item = syn::Signature> {
-        if mutable {
-            self.methods_ref_mut.iter()
-        } else {
-            self.methods_ref.iter()
+        match kind {
+            RefReceiver::Shared => self.methods_ref.iter(),
+            RefReceiver::Mut => self.methods_ref_mut.iter(),
+            RefReceiver::PinMut => self.methods_pin_mut.iter(),
         }
         .cloned()
         .map(|mut method| {
+            _ = method.sig.unpin_receiver();
             method.sig.lift_receiver_lifetime(parse_quote! { '__delegate });
+            for arg in &mut method.sig.inputs {
+                let syn::FnArg::Typed(arg) = arg else {
+                    continue;
+                };
+                if let Some(self_arg) = SelfArg::parse(&arg.ty) {
+                    let assoc = self_arg.assoc_ident();
+                    arg.ty = parse_quote! { Self::#assoc };
+                }
+            }
+            if method.sig.returns_self() {
+                let assoc = SelfArg::Owned.assoc_ident();
+                method.sig.output = parse_quote! { -> Self::#assoc };
+            }
             method.sig
         })
     }
 
+    /// Returns [`Ident`] of the trait generated by the
+    /// [`Self::generate_ref_trait()`] method for the provided [`RefReceiver`].
+    ///
+    /// [`Ident`]: struct@syn::Ident
+    const fn ref_trait_ident_of(&self, kind: RefReceiver) -> &syn::Ident {
+        match kind {
+            RefReceiver::Shared => &self.ref_trait_ident,
+            RefReceiver::Mut => &self.ref_mut_trait_ident,
+            RefReceiver::PinMut => &self.pin_mut_trait_ident,
+        }
+    }
+
+    /// Returns [`Generics`] for the trait generated by the
+    /// [`Self::generate_owned_trait()`] method.
+    ///
+    /// `Self:` bounds (like supertraits) are omitted, as they're required only
+    /// for the delegated trait itself, but not for the `Either`s implementing
+    /// the generated traits.
+    fn owned_trait_generics(&self) -> syn::Generics {
+        let mut gens = self.generics.clone();
+        gens.remove_self_ty_bounds();
+        gens
+    }
+
     /// Returns [`Generics`] for traits generated by the
     /// [`Self::generate_ref_trait()`] method.
     fn ref_trait_generics(&self) -> syn::Generics {
-        let mut gens = self.generics.clone();
+        let mut gens = self.owned_trait_generics();
         gens.params.push(parse_quote! { '__delegate });
         gens.make_where_clause()
             .predicates
@@ -1143,40 +2835,67 @@ impl Definition {
         gens
     }
 
+    /// Returns all the methods and associated functions of the trait.
+    fn methods(&self) -> impl Iterator<Item = &syn::TraitItemFn> {
+        self.methods_owned
+            .iter()
+            .chain(&self.methods_ref)
+            .chain(&self.methods_ref_mut)
+            .chain(&self.methods_pin_mut)
+            .chain(&self.methods_static)
+    }
+
     /// Returns [`Type`]s specified in method [`Signature`]s and their
     /// [`Generics`].
     fn methods_types(
         &self,
     ) -> impl Iterator<Item = (syn::Generics, syn::Type)> {
-        self.methods_owned
-            .iter()
-            .chain(&self.methods_ref)
-            .chain(&self.methods_ref_mut)
-            .flat_map(|m| {
-                let mut sig = m.sig.clone();
-
-                let mut lt_count: usize = 0;
-                sig.expand_lifetimes(parse_quote! { '__delegate }, || {
-                    lt_count += 1;
-                    syn::Lifetime::new(
-                        &format!("'__delegate{lt_count}"),
-                        Span::call_site(),
-                    )
-                });
+        self.methods().flat_map(|m| {
+            let mut sig = m.sig.clone();
+            _ = sig.unpin_receiver();
+
+            let mut lt_count: usize = 0;
+            sig.expand_lifetimes(parse_quote! { '__delegate }, || {
+                lt_count += 1;
+                syn::Lifetime::new(
+                    &format!("'__delegate{lt_count}"),
+                    Span::call_site(),
+                )
+            });
 
-                let output = (sig.generics.clone(), sig.return_type());
+            // Opaque types cannot be bound, so only the types specified in
+            // their bounds are.
+            let opaque = sig.impl_trait_output().is_some();
+            let outputs = if opaque {
+                sig.clone()
+                    .impl_trait_output_types_mut()
+                    .into_iter()
+                    .map(|ty| (sig.generics.clone(), ty.clone()))
+                    .collect()
+            } else if sig.returns_self() {
+                vec![]
+            } else {
+                vec![(sig.generics.clone(), sig.return_type())]
+            };
 
-                sig.inputs
-                    .iter()
-                    .filter_map(move |i| match i {
-                        syn::FnArg::Receiver(_) => None,
-                        syn::FnArg::Typed(t) => {
-                            Some((sig.generics.clone(), (*t.ty).clone()))
-                        }
-                    })
-                    .chain(iter::once(output))
-                    .collect::<Vec<_>>()
-            })
+            sig.inputs
+                .iter()
+                .filter_map(move |i| match i {
+                    syn::FnArg::Receiver(_) => None,
+                    // See `Self::bind_signature_types()` for details.
+                    syn::FnArg::Typed(t)
+                        if opaque && t.ty.contains_lifetimes()
+                            || SelfArg::parse(&t.ty).is_some() =>
+                    {
+                        None
+                    }
+                    syn::FnArg::Typed(t) => {
+                        Some((sig.generics.clone(), (*t.ty).clone()))
+                    }
+                })
+                .chain(outputs)
+                .collect::<Vec<_>>()
+        })
     }
 
     /// Replaces all [`Type`]s in the provided [`Signature`] with bound
@@ -1191,6 +2910,7 @@ impl Definition {
 
         let gens = {
             let mut sig = orig.clone();
+            _ = sig.unpin_receiver();
 
             let lt: syn::Lifetime = parse_quote! { '_ };
             sig.expand_lifetimes(lt.clone(), || lt.clone());
@@ -1200,36 +2920,78 @@ impl Definition {
             gens
         };
         let (_, ty_gens, _) = gens.split_for_impl();
+        let ty_gens = ty_gens.to_token_stream();
+
+        // Lifetimes of argument types become early-bound, once captured by an
+        // opaque return type, so such arguments are left as is, while the
+        // other ones are bound with `'static` lifetimes.
+        let opaque = orig.impl_trait_output().is_some();
+        let static_ty_gens = {
+            let args = gens.params.iter().map(|p| match p {
+                syn::GenericParam::Lifetime(_) => quote! { 'static },
+                syn::GenericParam::Type(t) => t.ident.to_token_stream(),
+                syn::GenericParam::Const(c) => c.ident.to_token_stream(),
+            });
+            quote! { < #( #args ),* > }
+        };
 
-        let mut bind_ty = || {
+        let mut bind_ty = |args: &TokenStream| {
             let bind_ident = format_ident!("{}{seq_num}", &self.bind_ident);
             *seq_num += 1;
 
             match &self.item {
                 Item::Definition(_) => {
                     parse_quote! {
-                        <Self::#bind_ident #ty_gens
+                        <Self::#bind_ident #args
                              as #macro_path ::TypeOf>::T
                     }
                 }
                 Item::External(_) => {
                     parse_quote! {
                         <<#macro_path ::External
-                          as #ident<Self>>::#bind_ident #ty_gens
+                          as #ident<Self>>::#bind_ident #args
                              as #macro_path ::TypeOf>::T
                     }
                 }
             }
         };
 
+        // `Self` arguments and return types are left as is, as `Self` is the
+        // type the trait is implemented for.
         let mut binded = orig.clone();
+        // `Pin` of the receiver is qualified, as it may be not imported where
+        // the trait is implemented.
+        if let Some(syn::FnArg::Receiver(rec)) = binded.inputs.first_mut() {
+            if let Some(lifetime) =
+                rec.pinned_reference().map(|r| r.lifetime.clone())
+            {
+                *rec.ty = parse_quote! {
+                    ::core::pin::Pin<&#lifetime mut Self>
+                };
+            }
+        }
         binded.inputs.iter_mut().for_each(|i| {
             if let syn::FnArg::Typed(ty) = i {
-                ty.ty = bind_ty();
+                if SelfArg::parse(&ty.ty).is_some() {
+                    return;
+                }
+                if !opaque {
+                    ty.ty = bind_ty(&ty_gens);
+                } else if !ty.ty.contains_lifetimes() {
+                    ty.ty = bind_ty(&static_ty_gens);
+                }
             }
         });
-        binded.output =
-            syn::ReturnType::Type(token::RArrow::default(), bind_ty());
+        if opaque {
+            for ty in binded.impl_trait_output_types_mut() {
+                *ty = *bind_ty(&ty_gens);
+            }
+        } else if !orig.returns_self() {
+            binded.output = syn::ReturnType::Type(
+                token::RArrow::default(),
+                bind_ty(&ty_gens),
+            );
+        }
         binded
     }
 }
@@ -1282,3 +3044,446 @@ impl Item {
         }
     }
 }
+
+/// Arguments of `#[delegate]` attribute on associated types.
+struct AssocTypeArgs {
+    /// `sum` attribute argument, specifying the wrapper of the generated sum
+    /// type.
+    sum: Option<SumWrapper>,
+
+    /// `supertrait` attribute argument, specifying the supertrait the
+    /// associated type belongs to.
+    supertrait: Option<syn::Path>,
+}
+
+impl AssocTypeArgs {
+    /// Creates new [`AssocTypeArgs`] from the provided [`Attribute`]s and
+    /// removes the corresponding attributes from the provided [`Attribute`]s.
+    ///
+    /// [`Attribute`]: syn::Attribute
+    fn from_attrs(
+        attrs: &mut Vec<syn::Attribute>,
+    ) -> syn::Result<Option<Self>> {
+        let args = attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("delegate"))
+            .map(syn::Attribute::parse_args::<Self>)
+            .at_most_one()
+            .map_err(|_err| {
+                syn::Error::new(
+                    Span::call_site(),
+                    "expected exactly one `#[delegate(..)]` attribute",
+                )
+            })?
+            .transpose()?;
+
+        attrs.retain(|attr| !attr.path().is_ident("delegate"));
+
+        Ok(args)
+    }
+}
+
+impl Parse for AssocTypeArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let arg = input.parse::<syn::Ident>()?;
+        if arg == "supertrait" {
+            _ = input.parse::<token::Eq>()?;
+            return Ok(Self { sum: None, supertrait: Some(input.parse()?) });
+        }
+        if arg != "sum" {
+            return Err(syn::Error::new(
+                arg.span(),
+                "unexpected attribute argument",
+            ));
+        }
+
+        let wrapper = if input.peek(token::As) {
+            _ = input.parse::<token::As>()?;
+            SumWrapper::External(input.parse()?)
+        } else {
+            SumWrapper::Local
+        };
+
+        Ok(Self { sum: Some(wrapper), supertrait: None })
+    }
+}
+
+/// Associated type of the delegated trait.
+#[derive(Clone, Debug)]
+struct AssocType {
+    /// Declaration of this [`AssocType`].
+    item: syn::TraitItemType,
+
+    /// Wrapper of the sum type generated for this [`AssocType`], if it's
+    /// marked with `#[delegate(sum)]`.
+    sum: Option<SumWrapper>,
+
+    /// Supertrait this [`AssocType`] belongs to, if it's marked with
+    /// `#[delegate(supertrait = path)]`.
+    ///
+    /// Such [`AssocType`]s are not defined in the implementations, but rather
+    /// required to be equal to the ones of the supertrait.
+    supertrait: Option<syn::Path>,
+}
+
+/// Wrapper of a sum type (an `Either` chain), implementing the bounds of an
+/// [`AssocType`] for it.
+#[derive(Clone, Debug)]
+enum SumWrapper {
+    /// `Wrapper` implementing crate-local delegated traits.
+    Local,
+
+    /// Wrapper of the provided external trait declaration.
+    External(syn::Path),
+}
+
+impl SumWrapper {
+    /// Returns the [`Type`] wrapping the provided one.
+    fn wrap_ty(&self, ty: &TokenStream, macro_path: &MacroPath) -> TokenStream {
+        match self {
+            Self::Local => quote! { #macro_path::Wrapper<#ty> },
+            Self::External(def) => quote! {
+                <#macro_path::External as #def<#ty>>::Wrapper
+            },
+        }
+    }
+
+    /// Returns an expression wrapping the provided one of the provided
+    /// [`Type`].
+    fn wrap_expr(
+        &self,
+        expr: &TokenStream,
+        ty: &TokenStream,
+        macro_path: &MacroPath,
+    ) -> TokenStream {
+        match self {
+            Self::Local => quote! { #macro_path::Wrapper(#expr) },
+            Self::External(def) => quote! {
+                <#macro_path::External as #def<#ty>>::wrap(#expr)
+            },
+        }
+    }
+}
+
+/// Arguments of `#[delegate]` attribute on associated constants.
+struct AssocConstArgs {
+    /// Name of the accessor method, specified by `accessor = name` attribute
+    /// argument (or [`None`] for the default one).
+    accessor: Option<syn::Ident>,
+}
+
+impl AssocConstArgs {
+    /// Creates new [`AssocConstArgs`] from the provided [`Attribute`]s and
+    /// removes the corresponding attributes from the provided [`Attribute`]s.
+    ///
+    /// [`Attribute`]: syn::Attribute
+    fn from_attrs(
+        attrs: &mut Vec<syn::Attribute>,
+    ) -> syn::Result<Option<Self>> {
+        let args = attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("delegate"))
+            .map(syn::Attribute::parse_args::<Self>)
+            .at_most_one()
+            .map_err(|_err| {
+                syn::Error::new(
+                    Span::call_site(),
+                    "expected exactly one `#[delegate(..)]` attribute",
+                )
+            })?
+            .transpose()?;
+
+        attrs.retain(|attr| !attr.path().is_ident("delegate"));
+
+        Ok(args)
+    }
+}
+
+impl Parse for AssocConstArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let arg = input.parse::<syn::Ident>()?;
+        if arg != "accessor" {
+            return Err(syn::Error::new(
+                arg.span(),
+                "unexpected attribute argument",
+            ));
+        }
+
+        let name = if input.peek(token::Eq) {
+            _ = input.parse::<token::Eq>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { accessor: name })
+    }
+}
+
+/// Associated constant of the delegated trait.
+#[derive(Clone, Debug)]
+struct AssocConst {
+    /// Declaration of this [`AssocConst`].
+    item: syn::TraitItemConst,
+}
+
+impl AssocConst {
+    /// Returns the accessor method of this [`AssocConst`] with the provided
+    /// name, generated for the ones marked with `#[delegate(accessor)]`.
+    fn accessor_method(&self, accessor: &syn::Ident) -> syn::TraitItemFn {
+        let const_ident = &self.item.ident;
+        let ty = &self.item.ty;
+
+        let doc = format!(
+            "Returns the [`Self::{const_ident}`] associated constant of this \
+             value (or of its delegate, if it's delegated).",
+        );
+
+        parse_quote! {
+            #[doc = #doc]
+            fn #accessor(&self) -> #ty {
+                Self::#const_ident
+            }
+        }
+    }
+}
+
+/// Arguments of `#[delegate]` attribute on trait methods.
+struct MethodArgs {
+    /// [`Path`] to the function to be called instead of the method, specified
+    /// by `mismatch = path` attribute argument, when the receiver and `Self`
+    /// arguments of the method hold different variants.
+    mismatch: Option<syn::Path>,
+
+    /// `skip` attribute argument, keeping the default implementation of the
+    /// method instead of delegating it.
+    skip: Option<syn::Ident>,
+}
+
+impl MethodArgs {
+    /// Creates new [`MethodArgs`] from the provided [`Attribute`]s and removes
+    /// the corresponding attributes from the provided [`Attribute`]s.
+    ///
+    /// [`Attribute`]: syn::Attribute
+    fn from_attrs(
+        attrs: &mut Vec<syn::Attribute>,
+    ) -> syn::Result<Option<Self>> {
+        let args = attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("delegate"))
+            .map(syn::Attribute::parse_args::<Self>)
+            .at_most_one()
+            .map_err(|_err| {
+                syn::Error::new(
+                    Span::call_site(),
+                    "expected exactly one `#[delegate(..)]` attribute",
+                )
+            })?
+            .transpose()?;
+
+        attrs.retain(|attr| !attr.path().is_ident("delegate"));
+
+        Ok(args)
+    }
+}
+
+impl Parse for MethodArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut this = Self { mismatch: None, skip: None };
+
+        while !input.is_empty() {
+            let arg = input.parse::<syn::Ident>()?;
+            if arg == "mismatch" {
+                _ = input.parse::<token::Eq>()?;
+                this.mismatch = Some(input.parse()?);
+            } else if arg == "skip" {
+                this.skip = Some(arg);
+            } else {
+                return Err(syn::Error::new(
+                    arg.span(),
+                    "unexpected attribute argument",
+                ));
+            }
+
+            if input.parse::<Option<token::Comma>>()?.is_none() {
+                break;
+            }
+        }
+
+        if let (Some(skip), Some(_)) = (&this.skip, &this.mismatch) {
+            return Err(syn::Error::new(
+                skip.span(),
+                "`skip` attribute argument cannot be combined with `mismatch`, \
+                 as skipped methods are never delegated",
+            ));
+        }
+
+        Ok(this)
+    }
+}
+
+/// Kind of a `Self` argument of trait methods, like `other: &Self`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SelfArg {
+    /// `Self` argument.
+    Owned,
+
+    /// `&Self` argument.
+    Ref,
+
+    /// `&mut Self` argument.
+    RefMut,
+}
+
+impl SelfArg {
+    /// Parses a [`SelfArg`] from the provided [`Type`] of an argument, if it's
+    /// a `Self`, `&Self` or `&mut Self` one.
+    fn parse(ty: &syn::Type) -> Option<Self> {
+        let is_self = |t: &syn::Type| {
+            matches!(
+                t,
+                syn::Type::Path(syn::TypePath { qself: None, path })
+                    if path.is_ident("Self"),
+            )
+        };
+
+        if is_self(ty) {
+            return Some(Self::Owned);
+        }
+        let syn::Type::Reference(r) = ty else {
+            return None;
+        };
+        is_self(&r.elem).then_some(if r.mutability.is_some() {
+            Self::RefMut
+        } else {
+            Self::Ref
+        })
+    }
+
+    /// Returns all the [`SelfArg`]s of the provided [`Signature`], along with
+    /// their patterns.
+    fn all_of(sig: &syn::Signature) -> Vec<(&syn::Pat, Self)> {
+        sig.inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::FnArg::Typed(arg) => {
+                    Some((arg.pat.as_ref(), Self::parse(&arg.ty)?))
+                }
+                syn::FnArg::Receiver(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the name of the associated type of the traits generated by the
+    /// [`Definition::generate_ref_trait()`] method, representing the `Either`
+    /// chain of this [`SelfArg`].
+    fn assoc_ident(self) -> syn::Ident {
+        match self {
+            Self::Owned => format_ident!("__Owned"),
+            Self::Ref => format_ident!("__Ref"),
+            Self::RefMut => format_ident!("__RefMut"),
+        }
+    }
+
+    /// Returns an expression converting the provided `arg` of this [`SelfArg`]
+    /// kind, being a wrapper of the provided `ty`, into its `Either` chain.
+    fn convert_expr(
+        self,
+        arg: &syn::Pat,
+        ty: &TokenStream,
+        macro_path: &MacroPath,
+    ) -> TokenStream {
+        match self {
+            Self::Owned => quote! {
+                <#ty as #macro_path::Convert>::convert_owned(#arg.0)
+            },
+            Self::Ref => quote! {
+                <#ty as #macro_path::Convert>::convert_ref(&#arg.0)
+            },
+            Self::RefMut => quote! {
+                <#ty as #macro_path::Convert>::convert_ref_mut(&mut #arg.0)
+            },
+        }
+    }
+}
+
+/// Smart pointer of a typed `self` receiver, like `self: Box<Self>`.
+#[derive(Clone, Debug)]
+struct ReceiverPointer {
+    /// [`Path`] to this [`ReceiverPointer`], as written in the receiver, but
+    /// without generic arguments.
+    path: syn::Path,
+
+    /// Indicator whether this [`ReceiverPointer`] is a shared one (like `Rc`
+    /// or `Arc`), so its value cannot be moved out of it, unless it's unique.
+    shared: bool,
+}
+
+impl ReceiverPointer {
+    /// Parses a [`ReceiverPointer`] from the provided [`syn::Receiver`], if it
+    /// has a `Box<Self>`, `Rc<Self>` or `Arc<Self>` type.
+    fn parse(receiver: &syn::Receiver) -> Option<Self> {
+        receiver.colon_token?;
+        let syn::Type::Path(syn::TypePath { qself: None, path }) =
+            receiver.ty.as_ref()
+        else {
+            return None;
+        };
+
+        let mut path = path.clone();
+        let last = path.segments.last_mut()?;
+        let shared = match last.ident.to_string().as_str() {
+            "Box" => false,
+            "Rc" | "Arc" => true,
+            _ => return None,
+        };
+        let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+            return None;
+        };
+        let Some(syn::GenericArgument::Type(syn::Type::Path(arg))) =
+            args.args.iter().exactly_one().ok()
+        else {
+            return None;
+        };
+        if arg.qself.is_some() || !arg.path.is_ident("Self") {
+            return None;
+        }
+        last.arguments = syn::PathArguments::None;
+
+        Some(Self { path, shared })
+    }
+
+    /// Wraps the provided `expr` into this [`ReceiverPointer`].
+    fn wrap_expr(&self, expr: &TokenStream) -> TokenStream {
+        let path = &self.path;
+
+        quote! { #path::new(#expr) }
+    }
+}
+
+/// Kind of a reference receiver of trait methods.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RefReceiver {
+    /// `&self` receiver.
+    Shared,
+
+    /// `&mut self` receiver.
+    Mut,
+
+    /// `self: Pin<&mut Self>` receiver.
+    PinMut,
+}
+
+impl RefReceiver {
+    /// Wraps the provided `ty` into a reference of this [`RefReceiver`] kind,
+    /// having the `'__delegate` lifetime.
+    fn wrap_ty(self, ty: &TokenStream) -> TokenStream {
+        match self {
+            Self::Shared => quote! { &'__delegate #ty },
+            Self::Mut => quote! { &'__delegate mut #ty },
+            Self::PinMut => {
+                quote! { ::core::pin::Pin<&'__delegate mut #ty> }
+            }
+        }
+    }
+}
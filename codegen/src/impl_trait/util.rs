@@ -7,11 +7,14 @@ use std::{
     iter, mem,
 };
 
+use itertools::Itertools as _;
+use proc_macro2::Span;
 use quote::quote;
 #[cfg(doc)]
 use syn::{Generics, Receiver, ReturnType, Signature, Type};
 use syn::{
     parse_quote, punctuated,
+    spanned::Spanned as _,
     visit::{self, Visit},
     visit_mut::{self, VisitMut},
 };
@@ -33,6 +36,19 @@ pub(super) trait GenericsExt {
 
     /// Removes `Self:` bounds from these [`Generics`].
     fn remove_self_ty_bounds(&mut self);
+
+    /// Removes all the bounds mentioning `Self` (like `Self:` or `Self::Assoc`
+    /// ones) from these [`Generics`].
+    fn remove_self_mentioning_bounds(&mut self);
+
+    /// Returns generic arguments corresponding to these [`Generics`], with the
+    /// provided `extra` ones (like associated type bindings) appended to them.
+    fn to_arguments_with<I>(
+        &self,
+        extra: I,
+    ) -> syn::AngleBracketedGenericArguments
+    where
+        I: IntoIterator<Item = syn::GenericArgument>;
 }
 
 impl GenericsExt for syn::Generics {
@@ -70,6 +86,12 @@ impl GenericsExt for syn::Generics {
 
             /// Collected [`syn::Type`]s.
             types: HashMap<syn::Type, Vec<syn::Lifetime>>,
+
+            /// [`syn::Type`]s enclosing the currently visited one.
+            ///
+            /// Doesn't cross references, because the lifetimes behind a
+            /// reference are not required to outlive the reference itself.
+            enclosing: Vec<syn::Type>,
         }
 
         impl CollectTypesWithLifetimesVisitor {
@@ -87,6 +109,7 @@ impl GenericsExt for syn::Generics {
                         .map(|lt| lt.ident.clone())
                         .collect(),
                     types: HashMap::new(),
+                    enclosing: Vec::new(),
                 }
             }
         }
@@ -94,12 +117,11 @@ impl GenericsExt for syn::Generics {
         impl<'ast> Visit<'ast> for CollectTypesWithLifetimesVisitor {
             fn visit_lifetime(&mut self, i: &'ast syn::Lifetime) {
                 if self.lifetimes.contains(&i.ident) {
-                    #[expect( // order doesn't matter here
-                        clippy::iter_over_hash_type,
-                        reason = "order doesn't matter here"
-                    )]
-                    for lifetimes in self.types.values_mut() {
-                        lifetimes.push(i.clone());
+                    for ty in &self.enclosing {
+                        self.types
+                            .entry(ty.clone())
+                            .or_default()
+                            .push(i.clone());
                     }
                 }
 
@@ -107,9 +129,35 @@ impl GenericsExt for syn::Generics {
             }
 
             fn visit_type(&mut self, i: &'ast syn::Type) {
-                drop(self.types.insert(i.clone(), Vec::new()));
+                _ = self.types.entry(i.clone()).or_default();
+                self.enclosing.push(i.clone());
+
+                if let syn::Type::Reference(r) = i {
+                    if let Some(lt) = &r.lifetime {
+                        self.visit_lifetime(lt);
+
+                        // `&'a T` is well-formed only if `T: 'a`.
+                        if self.lifetimes.contains(&lt.ident) {
+                            // Parentheses (like in `&'a (dyn Trait + 'b)`) are
+                            // unnecessary in bounds.
+                            let mut elem = r.elem.as_ref();
+                            if let syn::Type::Paren(p) = elem {
+                                elem = &p.elem;
+                            }
+                            self.types
+                                .entry(elem.clone())
+                                .or_default()
+                                .push(lt.clone());
+                        }
+                    }
+                    let enclosing = mem::take(&mut self.enclosing);
+                    self.visit_type(&r.elem);
+                    self.enclosing = enclosing;
+                } else {
+                    visit::visit_type(self, i);
+                }
 
-                visit::visit_type(self, i);
+                drop(self.enclosing.pop());
             }
         }
 
@@ -173,6 +221,237 @@ impl GenericsExt for syn::Generics {
             where_clause.predicates.push(pred);
         }
     }
+
+    fn remove_self_mentioning_bounds(&mut self) {
+        /// Checker whether the visited AST mentions `Self` [`Type`].
+        struct MentionsSelf(bool);
+
+        impl<'ast> Visit<'ast> for MentionsSelf {
+            fn visit_path(&mut self, i: &'ast syn::Path) {
+                if i.segments.first().is_some_and(|s| s.ident == "Self") {
+                    self.0 = true;
+                }
+
+                visit::visit_path(self, i);
+            }
+        }
+
+        let mentions_self = |bound: &syn::TypeParamBound| {
+            let mut visitor = MentionsSelf(false);
+            visitor.visit_type_param_bound(bound);
+            visitor.0
+        };
+
+        for param in self.type_params_mut() {
+            param.bounds = mem::take(&mut param.bounds)
+                .into_iter()
+                .filter(|b| !mentions_self(b))
+                .collect();
+        }
+
+        let Some(where_clause) = &mut self.where_clause else {
+            return;
+        };
+        for pred in mem::take(&mut where_clause.predicates) {
+            let mut visitor = MentionsSelf(false);
+            visitor.visit_where_predicate(&pred);
+            if !visitor.0 {
+                where_clause.predicates.push(pred);
+            }
+        }
+    }
+
+    fn to_arguments_with<I>(
+        &self,
+        extra: I,
+    ) -> syn::AngleBracketedGenericArguments
+    where
+        I: IntoIterator<Item = syn::GenericArgument>,
+    {
+        // `TypeGenerics` already takes care of placing lifetimes first.
+        let (_, ty_gens, _) = self.split_for_impl();
+        let mut args: syn::AngleBracketedGenericArguments =
+            if self.params.is_empty() {
+                parse_quote! { <> }
+            } else {
+                parse_quote! { #ty_gens }
+            };
+        args.args.extend(extra);
+        args
+    }
+}
+
+/// Helper extension of a [`syn::Type`].
+pub(super) trait TypeExt {
+    /// Qualifies all the `Self::Assoc` paths (and `<Self as ..>::Assoc` ones)
+    /// referring to the provided associated types with the `<ty as trait>::`
+    /// prefix.
+    ///
+    /// Returns `true` if anything has been qualified.
+    fn qualify_self_assoc_types(
+        &mut self,
+        assoc_types: &HashSet<syn::Ident>,
+        ty: &syn::Type,
+        trait_path: &syn::Path,
+    ) -> bool;
+}
+
+impl TypeExt for syn::Type {
+    fn qualify_self_assoc_types(
+        &mut self,
+        assoc_types: &HashSet<syn::Ident>,
+        ty: &syn::Type,
+        trait_path: &syn::Path,
+    ) -> bool {
+        let mut visitor = QualifySelfAssocTypes {
+            assoc_types,
+            ty,
+            trait_path,
+            matched: false,
+        };
+        visitor.visit_type_mut(self);
+        visitor.matched
+    }
+}
+
+/// Helper extension of a [`syn::WherePredicate`].
+pub(super) trait WherePredicateExt {
+    /// Qualifies all the `Self::Assoc` paths (and `<Self as ..>::Assoc` ones)
+    /// referring to the provided associated types with the `<ty as trait>::`
+    /// prefix.
+    fn qualify_self_assoc_types(
+        &mut self,
+        assoc_types: &HashSet<syn::Ident>,
+        ty: &syn::Type,
+        trait_path: &syn::Path,
+    );
+}
+
+impl WherePredicateExt for syn::WherePredicate {
+    fn qualify_self_assoc_types(
+        &mut self,
+        assoc_types: &HashSet<syn::Ident>,
+        ty: &syn::Type,
+        trait_path: &syn::Path,
+    ) {
+        QualifySelfAssocTypes { assoc_types, ty, trait_path, matched: false }
+            .visit_where_predicate_mut(self);
+    }
+}
+
+/// Visitor qualifying `Self::Assoc` paths with the `<ty as trait>::` prefix.
+struct QualifySelfAssocTypes<'a> {
+    /// Names of the associated types to be qualified.
+    assoc_types: &'a HashSet<syn::Ident>,
+
+    /// [`Type`] to be placed instead of `Self`.
+    ty: &'a syn::Type,
+
+    /// Path of the trait the associated types belong to.
+    trait_path: &'a syn::Path,
+
+    /// Indicator whether anything has been qualified.
+    matched: bool,
+}
+
+impl VisitMut for QualifySelfAssocTypes<'_> {
+    fn visit_type_path_mut(&mut self, i: &mut syn::TypePath) {
+        let refers_assoc_type = |seg: Option<&syn::PathSegment>| {
+            seg.is_some_and(|s| self.assoc_types.contains(&s.ident))
+        };
+
+        if let Some(qself) = &mut i.qself {
+            if matches!(
+                qself.ty.as_ref(),
+                syn::Type::Path(syn::TypePath { qself: None, path })
+                    if path.is_ident("Self"),
+            ) && refers_assoc_type(
+                i.path.segments.iter().nth(qself.position),
+            ) {
+                *qself.ty = self.ty.clone();
+                self.matched = true;
+            }
+        } else if i.path.leading_colon.is_none()
+            && i.path.segments.len() > 1
+            && i.path.segments.first().is_some_and(|s| s.ident == "Self")
+            && refers_assoc_type(i.path.segments.iter().nth(1))
+        {
+            let (ty, trait_path) = (self.ty, self.trait_path);
+            let rest = i.path.segments.iter().skip(1);
+            *i = parse_quote! { <#ty as #trait_path>::#( #rest )::* };
+            self.matched = true;
+        }
+
+        visit_mut::visit_type_path_mut(self, i);
+    }
+}
+
+/// Visitor unqualifying `<Self as ..>::Assoc` paths into `Self::Assoc` ones.
+struct UnqualifySelfAssocTypes<'a> {
+    /// Names of the associated types to be unqualified.
+    assoc_types: &'a HashSet<syn::Ident>,
+}
+
+impl VisitMut for UnqualifySelfAssocTypes<'_> {
+    fn visit_type_path_mut(&mut self, i: &mut syn::TypePath) {
+        if let Some(qself) = &i.qself {
+            let is_self = matches!(
+                qself.ty.as_ref(),
+                syn::Type::Path(syn::TypePath { qself: None, path })
+                    if path.is_ident("Self"),
+            );
+            if is_self
+                && i.path
+                    .segments
+                    .iter()
+                    .nth(qself.position)
+                    .is_some_and(|s| self.assoc_types.contains(&s.ident))
+            {
+                let rest = i.path.segments.iter().skip(qself.position);
+                *i = parse_quote! { Self::#( #rest )::* };
+            }
+        }
+
+        visit_mut::visit_type_path_mut(self, i);
+    }
+}
+
+/// Helper extension of a [`Receiver`].
+pub(super) trait ReceiverExt {
+    /// Returns the `&mut Self` reference, if this [`Receiver`] is a
+    /// `self: Pin<&mut Self>` one.
+    fn pinned_reference(&self) -> Option<&syn::TypeReference>;
+}
+
+impl ReceiverExt for syn::Receiver {
+    fn pinned_reference(&self) -> Option<&syn::TypeReference> {
+        self.colon_token?;
+        let syn::Type::Path(syn::TypePath { qself: None, path }) =
+            self.ty.as_ref()
+        else {
+            return None;
+        };
+        let last = path.segments.last()?;
+        if last.ident != "Pin" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+            return None;
+        };
+        let Some(syn::GenericArgument::Type(syn::Type::Reference(r))) =
+            args.args.iter().exactly_one().ok()
+        else {
+            return None;
+        };
+        let syn::Type::Path(elem) = r.elem.as_ref() else {
+            return None;
+        };
+
+        (r.mutability.is_some()
+            && elem.qself.is_none()
+            && elem.path.is_ident("Self"))
+        .then_some(r)
+    }
 }
 
 /// Helper extension of a [`syn::Signature`].
@@ -216,6 +495,60 @@ pub(super) trait SignatureExt {
     /// [`Lifetime`]: struct@syn::Lifetime
     /// [`rust-lang/rust#87803`]: https://github.com/rust-lang/rust/issues/87803
     fn to_be_early_bounded_lifetimes(&self) -> HashSet<syn::Lifetime>;
+
+    /// Unqualifies all the `<Self as ..>::Assoc` paths referring to the
+    /// provided associated types into the `Self::Assoc` ones.
+    ///
+    /// This method is useful, when this [`Signature`] is moved into another
+    /// trait declaring the same associated types.
+    fn unqualify_self_assoc_types(&mut self, assoc_types: &HashSet<syn::Ident>);
+
+    /// Returns the associated type (one of the provided ones), if this
+    /// [`Signature`]'s [`ReturnType`] is exactly `Self::Assoc`.
+    fn returns_self_assoc_type(
+        &self,
+        assoc_types: &HashSet<syn::Ident>,
+    ) -> Option<&syn::Ident>;
+
+    /// Checks whether this [`Signature`]'s [`ReturnType`] is exactly `Self`.
+    fn returns_self(&self) -> bool;
+
+    /// Checks whether this [`Signature`]'s [`ReturnType`] contains the
+    /// provided [`Lifetime`].
+    ///
+    /// [`Lifetime`]: struct@syn::Lifetime
+    fn output_contains_lifetime(&self, lifetime: &syn::Lifetime) -> bool;
+
+    /// Returns [`Span`] of the first `Self::Assoc` path in this [`Signature`]
+    /// referring to one of the provided associated types, if any.
+    fn find_self_assoc_types(
+        &self,
+        assoc_types: &HashSet<syn::Ident>,
+    ) -> Option<Span>;
+
+    /// Replaces the `self: Pin<&mut Self>` [`Receiver`] of this [`Signature`]
+    /// with the `&mut self` one, preserving its [`Lifetime`].
+    ///
+    /// Returns `false` if this [`Signature`] has no such [`Receiver`].
+    ///
+    /// This method is useful, when a pinned [`Receiver`] should be treated as
+    /// a regular reference one (for example, when expanding [`Lifetime`]s).
+    ///
+    /// [`Lifetime`]: struct@syn::Lifetime
+    fn unpin_receiver(&mut self) -> bool;
+
+    /// Desugars this `async` [`Signature`] into the one returning an
+    /// `impl Future`.
+    ///
+    /// Returns `false` if this [`Signature`] is not `async`.
+    fn desugar_async(&mut self) -> bool;
+
+    /// Returns the `impl Trait` [`ReturnType`] of this [`Signature`], if any.
+    fn impl_trait_output(&self) -> Option<&syn::TypeImplTrait>;
+
+    /// Returns the [`Type`]s used in generic arguments and associated types
+    /// bindings of the `impl Trait` [`ReturnType`] of this [`Signature`].
+    fn impl_trait_output_types_mut(&mut self) -> Vec<&mut syn::Type>;
 }
 
 impl SignatureExt for syn::Signature {
@@ -470,6 +803,181 @@ impl SignatureExt for syn::Signature {
 
         collector.lifetimes
     }
+
+    fn unqualify_self_assoc_types(
+        &mut self,
+        assoc_types: &HashSet<syn::Ident>,
+    ) {
+        UnqualifySelfAssocTypes { assoc_types }.visit_signature_mut(self);
+    }
+
+    fn returns_self_assoc_type(
+        &self,
+        assoc_types: &HashSet<syn::Ident>,
+    ) -> Option<&syn::Ident> {
+        let syn::ReturnType::Type(_, ty) = &self.output else {
+            return None;
+        };
+        let syn::Type::Path(syn::TypePath { qself: None, path }) = ty.as_ref()
+        else {
+            return None;
+        };
+
+        match path.segments.iter().collect::<Vec<_>>().as_slice() {
+            [self_, assoc]
+                if path.leading_colon.is_none()
+                    && self_.ident == "Self"
+                    && self_.arguments.is_none()
+                    && assoc.arguments.is_none()
+                    && assoc_types.contains(&assoc.ident) =>
+            {
+                Some(&assoc.ident)
+            }
+            _ => None,
+        }
+    }
+
+    fn returns_self(&self) -> bool {
+        let syn::ReturnType::Type(_, ty) = &self.output else {
+            return false;
+        };
+        matches!(
+            ty.as_ref(),
+            syn::Type::Path(syn::TypePath { qself: None, path })
+                if path.is_ident("Self"),
+        )
+    }
+
+    fn output_contains_lifetime(&self, lifetime: &syn::Lifetime) -> bool {
+        /// Finder of a [`Lifetime`].
+        ///
+        /// [`Lifetime`]: struct@syn::Lifetime
+        struct FindLifetime<'a> {
+            /// [`Lifetime`] to be found.
+            ///
+            /// [`Lifetime`]: struct@syn::Lifetime
+            lifetime: &'a syn::Lifetime,
+
+            /// Indicator whether the [`Lifetime`] has been found.
+            ///
+            /// [`Lifetime`]: struct@syn::Lifetime
+            found: bool,
+        }
+
+        impl<'ast> Visit<'ast> for FindLifetime<'_> {
+            fn visit_lifetime(&mut self, i: &'ast syn::Lifetime) {
+                self.found |= i == self.lifetime;
+            }
+        }
+
+        let mut finder = FindLifetime { lifetime, found: false };
+        finder.visit_return_type(&self.output);
+        finder.found
+    }
+
+    fn find_self_assoc_types(
+        &self,
+        assoc_types: &HashSet<syn::Ident>,
+    ) -> Option<Span> {
+        /// Finder of the `Self::Assoc` paths.
+        struct FindSelfAssocTypes<'a> {
+            /// Names of the associated types to be found.
+            assoc_types: &'a HashSet<syn::Ident>,
+
+            /// [`Span`] of the first found path.
+            found: Option<Span>,
+        }
+
+        impl<'ast> Visit<'ast> for FindSelfAssocTypes<'_> {
+            fn visit_type_path(&mut self, i: &'ast syn::TypePath) {
+                let mut segments = i.path.segments.iter();
+                if self.found.is_none()
+                    && i.qself.is_none()
+                    && segments.next().is_some_and(|s| s.ident == "Self")
+                    && segments
+                        .next()
+                        .is_some_and(|s| self.assoc_types.contains(&s.ident))
+                {
+                    self.found = Some(i.span());
+                }
+
+                visit::visit_type_path(self, i);
+            }
+        }
+
+        let mut finder = FindSelfAssocTypes { assoc_types, found: None };
+        finder.visit_signature(self);
+        finder.found
+    }
+
+    fn unpin_receiver(&mut self) -> bool {
+        let Some(syn::FnArg::Receiver(rec)) = self.inputs.first_mut() else {
+            return false;
+        };
+        let Some(lifetime) = rec.pinned_reference().map(|r| r.lifetime.clone())
+        else {
+            return false;
+        };
+
+        *rec = parse_quote! { &#lifetime mut self };
+        true
+    }
+
+    fn desugar_async(&mut self) -> bool {
+        if self.asyncness.take().is_none() {
+            return false;
+        }
+
+        let output = self.return_type();
+        self.output = parse_quote! {
+            -> impl ::core::future::Future<Output = #output>
+        };
+        true
+    }
+
+    fn impl_trait_output(&self) -> Option<&syn::TypeImplTrait> {
+        let syn::ReturnType::Type(_, ty) = &self.output else {
+            return None;
+        };
+        let syn::Type::ImplTrait(impl_trait) = &**ty else {
+            return None;
+        };
+        Some(impl_trait)
+    }
+
+    fn impl_trait_output_types_mut(&mut self) -> Vec<&mut syn::Type> {
+        let syn::ReturnType::Type(_, ty) = &mut self.output else {
+            return vec![];
+        };
+        let syn::Type::ImplTrait(impl_trait) = &mut **ty else {
+            return vec![];
+        };
+
+        let mut types = vec![];
+        for bound in &mut impl_trait.bounds {
+            let syn::TypeParamBound::Trait(t) = bound else {
+                continue;
+            };
+            for seg in &mut t.path.segments {
+                let syn::PathArguments::AngleBracketed(args) =
+                    &mut seg.arguments
+                else {
+                    continue;
+                };
+                for arg in &mut args.args {
+                    if let syn::GenericArgument::Type(arg_ty)
+                    | syn::GenericArgument::AssocType(syn::AssocType {
+                        ty: arg_ty,
+                        ..
+                    }) = arg
+                    {
+                        types.push(arg_ty);
+                    }
+                }
+            }
+        }
+        types
+    }
 }
 
 /// [`Iterator`] over [`Signature`]'s inputs, excluding its [`Receiver`].
@@ -565,6 +1073,10 @@ where
             let lt = (self.expand_fn)();
             i.lifetime = Some(lt.clone());
             self.expanded.push(lt);
+
+            // Expanded lifetime may be `'_` itself, so it shouldn't be visited
+            // again.
+            return self.visit_type_mut(&mut i.elem);
         }
 
         visit_mut::visit_type_reference_mut(self, i);
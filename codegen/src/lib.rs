@@ -388,18 +388,1070 @@ use self::macro_path::MacroPath;
 /// assert_eq!(name.as_str(), "John");
 /// ```
 ///
+/// # Associated types
+///
+/// Traits may declare associated types (generic associated types are not
+/// supported yet). All the delegates of an enum must agree on them: the
+/// delegated type uses the associated types of its first variant, and a variant
+/// with different ones is reported as a compile error.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Parser {
+///     type Output;
+///
+///     fn parse(&self, input: &str) -> Self::Output;
+/// }
+///
+/// struct Number;
+///
+/// impl Parser for Number {
+///     type Output = i64;
+///
+///     fn parse(&self, input: &str) -> i64 {
+///         input.parse().unwrap_or_default()
+///     }
+/// }
+///
+/// struct Length;
+///
+/// impl Parser for Length {
+///     type Output = i64;
+///
+///     fn parse(&self, input: &str) -> i64 {
+///         input.len().try_into().unwrap_or_default()
+///     }
+/// }
+///
+/// #[delegate(derive(Parser))]
+/// enum AnyParser {
+///     Number(Number),
+///     Length(Length),
+/// }
+///
+/// let number = AnyParser::Number(Number);
+/// let length = AnyParser::Length(Length);
+/// assert_eq!(number.parse("42"), 42);
+/// assert_eq!(length.parse("42"), 2);
+/// ```
+///
+/// If an associated type differs per variant, mark it with `#[delegate(sum)]`
+/// to make it a generated sum type of all the variants' ones, which implements
+/// the bounds of the associated type (if all the variants' ones do). The bounds
+/// must be crate-local `#[delegate]` traits, or, with
+/// `#[delegate(sum as my::Def)]`, an external trait declared by the provided
+/// `#[delegate(as = ..)]` declaration. Such associated types may only be used
+/// as a whole return type of trait methods.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate(as = Iterator)]
+/// trait IteratorDef {
+///     type Item;
+///
+///     fn next(&mut self) -> Option<Self::Item>;
+/// }
+///
+/// #[delegate(as = IntoIterator)]
+/// trait IntoIteratorDef {
+///     type Item;
+///
+///     #[delegate(sum as IteratorDef)]
+///     type IntoIter: Iterator<Item = Self::Item>;
+///
+///     fn into_iter(self) -> Self::IntoIter;
+/// }
+///
+/// #[delegate(derive(IntoIterator as IntoIteratorDef))]
+/// enum Numbers {
+///     List(Vec<u8>),
+///     Range(std::ops::Range<u8>),
+/// }
+///
+/// let list = Numbers::List(vec![1, 2]);
+/// let range = Numbers::Range(3..5);
+/// assert_eq!(list.into_iter().chain(range).collect::<Vec<_>>(), [1, 2, 3, 4]);
+/// ```
+///
+/// # Associated constants
+///
+/// Traits may declare associated constants. By default, all the delegates of an
+/// enum must share the same value of a constant, which is checked at compile
+/// time (so such constants are limited to integer, [`bool`], [`char`] and
+/// `&str` types). If a constant differs per variant (or has another type),
+/// mark it with `#[delegate(accessor)]` (or `#[delegate(accessor = name)]`) to
+/// generate a trait method returning the value of the current variant (named
+/// as the lowercased constant by default). Such a constant should have a
+/// default value, used by the delegated type itself, as its delegates may
+/// differ in it. Accessors are supported for crate-local traits only.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Protocol {
+///     #[delegate(accessor)]
+///     const KIND: &'static str = "any";
+///
+///     const PORT_RANGE: u16;
+/// }
+///
+/// struct Http;
+///
+/// impl Protocol for Http {
+///     const KIND: &'static str = "http";
+///     const PORT_RANGE: u16 = 1024;
+/// }
+///
+/// struct Ws;
+///
+/// impl Protocol for Ws {
+///     const KIND: &'static str = "ws";
+///     const PORT_RANGE: u16 = 1024;
+/// }
+///
+/// #[delegate(derive(Protocol))]
+/// enum AnyProtocol {
+///     Http(Http),
+///     Ws(Ws),
+/// }
+///
+/// assert_eq!(AnyProtocol::Http(Http).kind(), "http");
+/// assert_eq!(AnyProtocol::Ws(Ws).kind(), "ws");
+/// assert_eq!(AnyProtocol::KIND, "any");
+/// assert_eq!(AnyProtocol::PORT_RANGE, 1024);
+/// ```
+///
+/// # Pinned receivers
+///
+/// Trait methods may also have a `self: Pin<&mut Self>` receiver (like
+/// [`Future::poll()`] does). Delegating such methods requires a structural pin
+/// projection of the delegating type onto its delegates, so it should be
+/// explicitly opted in with the `pin` argument. In return, the delegating
+/// type:
+/// - implements [`Unpin`] only if all of its delegates do;
+/// - cannot implement [`Drop`] (use a delegate's one instead);
+/// - cannot be `#[repr(packed)]`.
+///
+/// ```rust
+/// # use std::{
+/// #     future::{self, Future},
+/// #     pin::{Pin, pin},
+/// #     task::{Context, Poll, Waker},
+/// # };
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate(as = Future)]
+/// trait FutureDef {
+///     type Output;
+///
+///     fn poll(
+///         self: Pin<&mut Self>,
+///         cx: &mut Context<'_>,
+///     ) -> Poll<Self::Output>;
+/// }
+///
+/// #[delegate(derive(Future as FutureDef), pin)]
+/// enum AnyFuture {
+///     Ready(future::Ready<u8>),
+///     Pending(future::Pending<u8>),
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// let fut = pin!(AnyFuture::Ready(future::ready(1)));
+/// assert_eq!(fut.poll(&mut cx), Poll::Ready(1));
+///
+/// let fut = pin!(AnyFuture::Pending(future::pending()));
+/// assert_eq!(fut.poll(&mut cx), Poll::Pending);
+/// ```
+///
+/// # Async methods and `impl Trait` return types
+///
+/// Trait methods may be `async` or return `impl Future` or `impl Iterator` (as
+/// well as `impl DoubleEndedIterator`, `impl ExactSizeIterator` or
+/// `impl FusedIterator`), possibly with auto traits like [`Send`]. As such
+/// opaque types differ per delegate, the delegating type returns their sum,
+/// implementing the same traits.
+///
+/// ```rust
+/// # use std::{
+/// #     future::Future,
+/// #     pin::pin,
+/// #     task::{Context, Poll, Waker},
+/// # };
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Storage {
+///     fn keys(&self) -> impl Iterator<Item = u8> + '_;
+///
+///     async fn len(&self) -> usize;
+/// }
+///
+/// struct Memory(Vec<u8>);
+///
+/// impl Storage for Memory {
+///     fn keys(&self) -> impl Iterator<Item = u8> + '_ {
+///         self.0.iter().copied()
+///     }
+///
+///     async fn len(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// struct Disk;
+///
+/// impl Storage for Disk {
+///     fn keys(&self) -> impl Iterator<Item = u8> + '_ {
+///         0..3
+///     }
+///
+///     async fn len(&self) -> usize {
+///         3
+///     }
+/// }
+///
+/// #[delegate(derive(Storage))]
+/// enum AnyStorage {
+///     Memory(Memory),
+///     Disk(Disk),
+/// }
+///
+/// let memory = AnyStorage::Memory(Memory(vec![4, 2]));
+/// assert_eq!(memory.keys().collect::<Vec<_>>(), [4, 2]);
+/// assert_eq!(AnyStorage::Disk(Disk).keys().sum::<u8>(), 3);
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(pin!(memory.len()).poll(&mut cx), Poll::Ready(2));
+/// ```
+///
+/// # Supertraits
+///
+/// Supertraits of a trait (as well as other `Self:` bounds of it) may be
+/// `#[delegate]` traits too. Such supertraits should be derived for the
+/// delegating type along with the trait itself, while their methods are called
+/// on delegates as usual.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+///
+/// #[delegate]
+/// trait Shape: Named + Send {
+///     fn corners(&self) -> usize;
+///
+///     fn describe(&self) -> String {
+///         format!("{} of {} corners", self.name(), self.corners())
+///     }
+/// }
+///
+/// struct Square;
+///
+/// impl Named for Square {
+///     fn name(&self) -> String {
+///         "square".into()
+///     }
+/// }
+///
+/// impl Shape for Square {
+///     fn corners(&self) -> usize {
+///         4
+///     }
+/// }
+///
+/// struct Triangle;
+///
+/// impl Named for Triangle {
+///     fn name(&self) -> String {
+///         "triangle".into()
+///     }
+/// }
+///
+/// impl Shape for Triangle {
+///     fn corners(&self) -> usize {
+///         3
+///     }
+/// }
+///
+/// #[delegate(derive(Named, Shape))]
+/// enum AnyShape {
+///     Square(Square),
+///     Triangle(Triangle),
+/// }
+///
+/// assert_eq!(AnyShape::Square(Square).describe(), "square of 4 corners");
+/// assert_eq!(AnyShape::Triangle(Triangle).name(), "triangle");
+/// ```
+///
+/// # Binary methods
+///
+/// Methods taking `Self` arguments (like `other: Self`, `other: &Self` or
+/// `other: &mut Self`) are delegated only when all the arguments hold the same
+/// variant as the receiver. Otherwise, the method's default implementation is
+/// called, or the function specified by the `#[delegate(mismatch = path)]`
+/// attribute (receiving the same arguments as the method). One of them is
+/// required for such methods.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Message {
+///     fn same_as(&self, other: &Self) -> bool {
+///         _ = other;
+///         false
+///     }
+///
+///     #[delegate(mismatch = replace)]
+///     fn merge(&mut self, other: Self);
+///
+///     fn text(&self) -> String;
+/// }
+///
+/// fn replace<T: Message>(this: &mut T, other: T) {
+///     *this = other;
+/// }
+///
+/// struct Text(String);
+///
+/// impl Message for Text {
+///     fn same_as(&self, other: &Self) -> bool {
+///         self.0 == other.0
+///     }
+///
+///     fn merge(&mut self, other: Self) {
+///         self.0.push_str(&other.0);
+///     }
+///
+///     fn text(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// struct Ping;
+///
+/// impl Message for Ping {
+///     fn same_as(&self, _: &Self) -> bool {
+///         true
+///     }
+///
+///     fn merge(&mut self, _: Self) {}
+///
+///     fn text(&self) -> String {
+///         "ping".into()
+///     }
+/// }
+///
+/// #[delegate(derive(Message))]
+/// enum AnyMessage {
+///     Text(Text),
+///     Ping(Ping),
+/// }
+///
+/// let mut msg = AnyMessage::Text(Text("foo".into()));
+/// assert!(msg.same_as(&AnyMessage::Text(Text("foo".into()))));
+/// assert!(!msg.same_as(&AnyMessage::Ping(Ping)));
+///
+/// msg.merge(AnyMessage::Text(Text("bar".into())));
+/// assert_eq!(msg.text(), "foobar");
+/// msg.merge(AnyMessage::Ping(Ping));
+/// assert_eq!(msg.text(), "ping");
+/// ```
+///
+/// # Methods returning `Self`
+///
+/// Methods returning `Self` (like
+/// `fn with_timeout(self, timeout: Duration) -> Self` or
+/// `fn normalized(&self) -> Self`) are delegated by putting the returned value
+/// back into the same variant (or struct field) the method was called on.
+/// Types having external delegates (marked with the `as` attribute argument)
+/// cannot delegate such methods, as the returned values cannot be converted
+/// back into them.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Timeout {
+///     fn timeout(&self) -> Duration;
+///
+///     fn with_timeout(self, timeout: Duration) -> Self;
+/// }
+///
+/// struct Http(Duration);
+///
+/// impl Timeout for Http {
+///     fn timeout(&self) -> Duration {
+///         self.0
+///     }
+///
+///     fn with_timeout(self, timeout: Duration) -> Self {
+///         Self(timeout)
+///     }
+/// }
+///
+/// struct Grpc(Duration);
+///
+/// impl Timeout for Grpc {
+///     fn timeout(&self) -> Duration {
+///         self.0
+///     }
+///
+///     fn with_timeout(self, timeout: Duration) -> Self {
+///         Self(timeout)
+///     }
+/// }
+///
+/// #[delegate(derive(Timeout))]
+/// enum Config {
+///     Http(Http),
+///     Grpc(Grpc),
+/// }
+///
+/// let config = Config::Grpc(Grpc(Duration::ZERO));
+/// let config = config.with_timeout(Duration::from_secs(5));
+/// assert!(matches!(config, Config::Grpc(_)));
+/// assert_eq!(config.timeout(), Duration::from_secs(5));
+/// ```
+///
+/// # Associated functions
+///
+/// Associated functions without receiver (like `fn new() -> Self` or
+/// `fn name() -> &'static str`) have no variant to be dispatched on, so they're
+/// delegated to the enum variant marked with `#[delegate(default)]` (or to the
+/// struct field), putting the returned `Self` into it. Enums without such
+/// variant cannot derive traits having associated functions.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Storage {
+///     fn new() -> Self;
+///
+///     fn name() -> &'static str;
+/// }
+///
+/// struct Memory(Vec<u8>);
+///
+/// impl Storage for Memory {
+///     fn new() -> Self {
+///         Self(Vec::new())
+///     }
+///
+///     fn name() -> &'static str {
+///         "memory"
+///     }
+/// }
+///
+/// struct Disk(String);
+///
+/// impl Storage for Disk {
+///     fn new() -> Self {
+///         Self("/tmp".into())
+///     }
+///
+///     fn name() -> &'static str {
+///         "disk"
+///     }
+/// }
+///
+/// #[delegate(derive(Storage))]
+/// enum AnyStorage {
+///     Memory(Memory),
+///     #[delegate(default)]
+///     Disk(Disk),
+/// }
+///
+/// assert_eq!(AnyStorage::name(), "disk");
+/// assert!(matches!(AnyStorage::new(), AnyStorage::Disk(_)));
+/// ```
+///
+/// # Skipped methods
+///
+/// Methods marked with `#[delegate(skip)]` are not delegated, keeping the
+/// default implementation of the trait for the delegating type, which is useful
+/// for methods computing aggregate info on top of the delegated ones. Methods
+/// may also be skipped for a single delegating type only, by listing them in
+/// the `derive(Trait(skip(..)))` attribute argument.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Shape {
+///     fn area(&self) -> f64;
+///
+///     #[delegate(skip)]
+///     fn describe(&self) -> String {
+///         format!("shape of {} area", self.area())
+///     }
+///
+///     fn name(&self) -> String {
+///         "shape".into()
+///     }
+/// }
+///
+/// struct Square(f64);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+///
+///     fn describe(&self) -> String {
+///         format!("square of {} side", self.0)
+///     }
+///
+///     fn name(&self) -> String {
+///         "square".into()
+///     }
+/// }
+///
+/// #[delegate(derive(Shape))]
+/// enum AnyShape {
+///     Square(Square),
+/// }
+///
+/// #[delegate(derive(Shape(skip(name))))]
+/// struct Unnamed(AnyShape);
+///
+/// let square = AnyShape::Square(Square(2.0));
+/// assert_eq!(square.describe(), "shape of 4 area");
+/// assert_eq!(square.name(), "square");
+///
+/// let unnamed = Unnamed(square);
+/// assert_eq!(unnamed.name(), "shape");
+/// ```
+///
+/// # Variants delegated to functions
+///
+/// Enum variants not implementing the trait (or requiring a special behavior)
+/// may be marked with the `#[delegate(with = path)]` attribute argument, so the
+/// functions under the provided `path` are called for them instead of
+/// delegating. Each function is named after the trait method, and accepts the
+/// variant field in place of the receiver, along with the rest of method
+/// arguments. In [binary methods](#binary-methods), the function is called
+/// whenever the receiver holds such variant, while `Self` arguments holding it
+/// are considered as mismatching otherwise.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Storage {
+///     fn get(&self, key: &str) -> Option<String>;
+///
+///     fn set(&mut self, key: &str, value: String);
+/// }
+///
+/// struct Memory(Vec<(String, String)>);
+///
+/// impl Storage for Memory {
+///     fn get(&self, key: &str) -> Option<String> {
+///         self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+///     }
+///
+///     fn set(&mut self, key: &str, value: String) {
+///         self.0.push((key.into(), value));
+///     }
+/// }
+///
+/// mod disabled {
+///     pub struct Disabled;
+///
+///     pub fn get(_: &Disabled, _: &str) -> Option<String> {
+///         None
+///     }
+///
+///     pub fn set(_: &mut Disabled, _: &str, _: String) {}
+/// }
+///
+/// #[delegate(derive(Storage))]
+/// enum AnyStorage {
+///     Memory(Memory),
+///     #[delegate(with = disabled)]
+///     Disabled(disabled::Disabled),
+/// }
+///
+/// let mut memory = AnyStorage::Memory(Memory(vec![]));
+/// memory.set("key", "value".into());
+/// assert_eq!(memory.get("key").as_deref(), Some("value"));
+///
+/// let mut disabled = AnyStorage::Disabled(disabled::Disabled);
+/// disabled.set("key", "value".into());
+/// assert_eq!(disabled.get("key"), None);
+/// ```
+///
+/// # Unit variants
+///
+/// Unit enum variants (and structs without fields) may be delegated to a value
+/// of a zero-sized type implementing the trait, specified via the
+/// `#[delegate(as_value = Type)]` attribute argument. The value is synthesized
+/// from the `Type` unit struct expression each time the variant is delegated
+/// to.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Storage {
+///     fn get(&self, key: &str) -> Option<String>;
+///
+///     fn set(&mut self, key: &str, value: String);
+/// }
+///
+/// struct Memory(Vec<(String, String)>);
+///
+/// impl Storage for Memory {
+///     fn get(&self, key: &str) -> Option<String> {
+///         self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+///     }
+///
+///     fn set(&mut self, key: &str, value: String) {
+///         self.0.push((key.into(), value));
+///     }
+/// }
+///
+/// struct Noop;
+///
+/// impl Storage for Noop {
+///     fn get(&self, _: &str) -> Option<String> {
+///         None
+///     }
+///
+///     fn set(&mut self, _: &str, _: String) {}
+/// }
+///
+/// #[delegate(derive(Storage))]
+/// enum AnyStorage {
+///     Memory(Memory),
+///     #[delegate(as_value = Noop)]
+///     Disabled,
+/// }
+///
+/// #[delegate(derive(Storage), as_value = Noop)]
+/// struct Disabled;
+///
+/// let mut disabled = AnyStorage::Disabled;
+/// disabled.set("key", "value".into());
+/// assert_eq!(disabled.get("key"), None);
+///
+/// let mut disabled = Disabled;
+/// disabled.set("key", "value".into());
+/// assert_eq!(disabled.get("key"), None);
+/// ```
+///
+/// # Multiple fields
+///
+/// Structs and enum variants with multiple fields should mark the one to
+/// delegate to with the `#[delegate(to)]` attribute, keeping the other fields
+/// untouched.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+///
+/// struct User(String);
+///
+/// impl Named for User {
+///     fn name(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// #[delegate(derive(Named))]
+/// struct Session {
+///     id: u64,
+///     #[delegate(to)]
+///     user: User,
+/// }
+///
+/// #[delegate(derive(Named))]
+/// enum Actor {
+///     User(User),
+///     Guest {
+///         #[delegate(to)]
+///         user: User,
+///         token: u64,
+///     },
+/// }
+///
+/// let session = Session { id: 1, user: User("Alice".into()) };
+/// assert_eq!(session.name(), "Alice");
+/// assert_eq!(session.id, 1);
+///
+/// let guest = Actor::Guest { user: User("Bob".into()), token: 2 };
+/// assert_eq!(guest.name(), "Bob");
+/// ```
+///
+/// # Traits delegated to fields
+///
+/// Struct fields may derive traits on their own via the
+/// `#[delegate(derive(..))]` attribute, so a composite struct delegates each
+/// trait to the field responsible for it. Such fields don't need to be marked
+/// with `#[delegate(to)]`.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+///
+/// #[delegate]
+/// trait Counted {
+///     fn count(&self) -> usize;
+/// }
+///
+/// struct User(String);
+///
+/// impl Named for User {
+///     fn name(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// struct Visits(usize);
+///
+/// impl Counted for Visits {
+///     fn count(&self) -> usize {
+///         self.0
+///     }
+/// }
+///
+/// #[delegate]
+/// struct Session {
+///     #[delegate(derive(Named))]
+///     user: User,
+///     #[delegate(derive(Counted))]
+///     visits: Visits,
+/// }
+///
+/// let session = Session { user: User("Alice".into()), visits: Visits(3) };
+/// assert_eq!(session.name(), "Alice");
+/// assert_eq!(session.count(), 3);
+/// ```
+///
+/// # Smart pointers
+///
+/// Enum variants and struct fields holding a `Box`, `Rc`, `Arc` or a reference
+/// may delegate to the value behind it, when marked with the
+/// `#[delegate(deref)]` attribute. Only these pointers are supported, so other
+/// `Deref` types (like `ManuallyDrop` or custom smart pointers) fail to
+/// compile, and should be delegated through [accessors](#accessors) instead.
+///
+/// `Box` supports all the receivers, unboxing the value for `self` ones and
+/// boxing it back for methods returning `Self`. `Rc`, `Arc` and `&T` support
+/// only `&self` receivers, while `&mut T` supports all the reference ones, so
+/// delegating other methods through them fails to compile.
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+///
+/// struct User(String);
+///
+/// impl Named for User {
+///     fn name(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// #[delegate(derive(Named))]
+/// enum Account<'a> {
+///     #[delegate(deref)]
+///     Owned(Box<User>),
+///     #[delegate(deref)]
+///     Shared(Rc<User>),
+///     #[delegate(deref)]
+///     Borrowed(&'a User),
+/// }
+///
+/// #[delegate(derive(Named))]
+/// struct Session {
+///     id: u64,
+///     #[delegate(to, deref)]
+///     user: Box<User>,
+/// }
+///
+/// let user = User("Alice".into());
+/// assert_eq!(Account::Owned(Box::new(User("Bob".into()))).name(), "Bob");
+/// assert_eq!(Account::Shared(Rc::new(User("Eve".into()))).name(), "Eve");
+/// assert_eq!(Account::Borrowed(&user).name(), "Alice");
+///
+/// let session = Session { id: 1, user: Box::new(user) };
+/// assert_eq!(session.name(), "Alice");
+/// ```
+///
+/// # Accessors
+///
+/// Structs may delegate to a value obtained from accessors rather than a field,
+/// like the active one among several backends, when marked with the
+/// `#[delegate(via = path -> Type)]` attribute, where `path` borrows the
+/// delegate of the `Type` from `&Self`. Enum variants may be marked with it
+/// too, so `path` borrows the delegate from their field.
+///
+/// `&mut self` methods require the `via_mut = path` accessor borrowing the
+/// delegate mutably, and `self` ones require the `via_owned = path` accessor
+/// moving it out, so delegating them fails to compile otherwise.
+///
+/// ```rust
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Named {
+///     fn name(&self) -> String;
+///
+///     fn rename(&mut self, name: &str);
+/// }
+///
+/// struct User(String);
+///
+/// impl Named for User {
+///     fn name(&self) -> String {
+///         self.0.clone()
+///     }
+///
+///     fn rename(&mut self, name: &str) {
+///         self.0 = name.into();
+///     }
+/// }
+///
+/// #[delegate(
+///     derive(Named),
+///     via = Self::current -> User,
+///     via_mut = Self::current_mut,
+/// )]
+/// struct Team {
+///     users: Vec<User>,
+///     lead: usize,
+/// }
+///
+/// impl Team {
+///     fn current(&self) -> &User {
+///         &self.users[self.lead]
+///     }
+///
+///     fn current_mut(&mut self) -> &mut User {
+///         &mut self.users[self.lead]
+///     }
+/// }
+///
+/// let mut team =
+///     Team { users: vec![User("Alice".into()), User("Bob".into())], lead: 1 };
+/// assert_eq!(team.name(), "Bob");
+/// team.rename("Eve");
+/// assert_eq!(team.users[1].name(), "Eve");
+/// ```
+///
+/// # Locks
+///
+/// Enum variants and struct fields holding a `Mutex`, `RwLock` or `RefCell` may
+/// be marked with the `#[delegate(lock)]` attribute to delegate to the value
+/// guarded by it. `&self` methods acquire the guard (a read one for `RwLock`),
+/// call the delegate and release the guard on return, while `&mut self` and
+/// `self` ones access the value directly, without locking. Poisoned locks are
+/// accessed regardless of their poisoning. `Mutex` and `RwLock` are supported
+/// only with the default `std` feature enabled.
+///
+/// As the guard is released on return, methods returning borrows of the
+/// delegate (as well as the ones returning `impl Trait`, having `Self`
+/// arguments or named receiver lifetimes) cannot be delegated through a lock,
+/// and fail to compile. The same goes for the inherent `&self` methods listed
+/// in `#[delegate(methods { .. })]` returning borrows (or `impl Trait`, or
+/// being `async`).
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate]
+/// trait Counter {
+///     fn count(&self) -> u32;
+///
+///     fn add(&mut self, n: u32);
+/// }
+///
+/// struct Hits(u32);
+///
+/// impl Counter for Hits {
+///     fn count(&self) -> u32 {
+///         self.0
+///     }
+///
+///     fn add(&mut self, n: u32) {
+///         self.0 += n;
+///     }
+/// }
+///
+/// #[delegate(derive(Counter))]
+/// struct Shared(#[delegate(lock)] Mutex<Hits>);
+///
+/// let mut shared = Shared(Mutex::new(Hits(1)));
+/// shared.add(2);
+/// assert_eq!(shared.count(), 3);
+/// ```
+///
+/// # Inherent methods
+///
+/// Inherent methods may be delegated without a trait by listing their
+/// signatures in the `#[delegate(methods { .. })]` attribute argument of a
+/// struct or an enum. Each listed method calls the same-named method of the
+/// delegate (or of each enum variant), while variants marked with
+/// `#[delegate(with = path)]` call the same-named function under the `path`.
+/// Attributes and visibility of the listed methods are preserved.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// #
+/// # use delegation::delegate;
+/// #
+/// #[delegate(methods {
+///     /// Returns the number of the stored entries.
+///     pub fn len(&self) -> usize;
+///     /// Removes all the stored entries.
+///     pub fn clear(&mut self);
+/// })]
+/// enum Entries {
+///     List(Vec<(String, u32)>),
+///     Map(HashMap<String, u32>),
+/// }
+///
+/// let mut entries = Entries::Map(HashMap::from([("a".into(), 1)]));
+/// assert_eq!(entries.len(), 1);
+/// entries.clear();
+/// assert_eq!(entries.len(), 0);
+/// ```
+///
+/// # Standard library traits
+///
+/// Ready-made definitions of the standard library traits are shipped in the
+/// `delegation::ext` module, so they may be delegated without writing
+/// `#[delegate(as = ..)]` definitions by hand:
+/// - `delegation::ext::error` defines the `core::error::Error` trait.
+/// - `delegation::ext::future` defines the `core::future::Future` trait,
+///   requiring the `#[delegate(pin)]` attribute argument on delegating types.
+/// - `delegation::ext::fmt` defines all the `core::fmt` formatting traits and
+///   `fmt::Write`.
+/// - `delegation::ext::io` (behind the default `std` feature) defines the
+///   `std::io::Read`, `Write`, `BufRead` and `Seek` traits.
+/// - `delegation::ext::iter` defines the `Iterator`, `DoubleEndedIterator` and
+///   `ExactSizeIterator` traits.
+///
+/// Provided methods of the traits are delegated too, so the optimized
+/// implementations of the delegates are used.
+///
+/// ```rust
+/// # use std::fmt;
+/// #
+/// # use delegation::{delegate, ext};
+/// #
+/// #[delegate(derive(
+///     fmt::Display as ext::fmt::Display,
+///     fmt::Debug as ext::fmt::Debug,
+/// ))]
+/// enum Id {
+///     Short(u8),
+///     Long(String),
+/// }
+///
+/// #[delegate(derive(fmt::LowerHex as ext::fmt::LowerHex))]
+/// struct Hash(u64);
+///
+/// assert_eq!(Id::Short(7).to_string(), "7");
+/// assert_eq!(format!("{:?}", Id::Long("a".into())), r#""a""#);
+/// assert_eq!(format!("{:x}", Hash(255)), "ff");
+/// ```
+///
 /// # Limitations
 ///
 /// - Both struct/enum and trait should be marked with `#[delegate]` macro
 ///   attribute.
-/// - Struct or enum variant should contain only single field.
-/// - Trait methods must have an untyped receiver.
-/// - Supertraits or `Self` trait/method bounds except marker traits like
-///   [`Sized`], [`Send`] or [`Sync`] are not supported yet.
-/// - Associated types/constants are not supported yet.
+/// - Struct or enum variant should contain only single field, unless it has
+///   no fields and is marked with `#[delegate(as_value = Type)]`, or marks one
+///   of its fields with `#[delegate(to)]`.
+/// - Trait methods must have an untyped receiver, or a `self: Box<Self>`,
+///   `self: Rc<Self>`, `self: Arc<Self>` or `self: Pin<&mut Self>` one. `Rc`
+///   and `Arc` ones pass a new pointer to the delegate, holding the value
+///   moved out of the original pointer if it's unique, or a clone of the
+///   delegate otherwise, so require delegates to implement [`Clone`] (and not
+///   be guarded by locks). `Pin` ones require the delegating type to be marked
+///   with the `pin` argument.
+/// - Supertraits and `Self` trait bounds should be either `#[delegate]`
+///   traits or marker traits like [`Sized`], [`Send`] or [`Sync`]. `Self`
+///   method bounds except marker traits are not supported yet.
+/// - Generic associated types are not supported yet.
+/// - Associated types must be the same for all the delegates, unless marked
+///   with `#[delegate(sum)]`.
+/// - Associated constants must be the same for all the delegates, and have an
+///   integer, [`bool`], [`char`] or `&str` type, unless marked with
+///   `#[delegate(accessor)]`.
+/// - `impl Trait` return types may only be bounded by [`Future`] or
+///   [`Iterator`] traits family and auto traits.
 /// - Lifetimes in methods are limited to be early-bounded in some cases
 ///   (see [rust-lang/rust#87803]).
-/// - `Self` type is limited to be used in methods return types.
+/// - Associated functions without receiver are delegated only to the enum
+///   variant marked with `#[delegate(default)]` (or to the struct field), and
+///   cannot return `#[delegate(sum)]` associated types.
+/// - Variants marked with `#[delegate(with = path)]` support only methods
+///   with untyped receivers and not returning `impl Trait`, and aren't
+///   supported by traits having `#[delegate(sum)]` associated types or
+///   implemented via the `for(..)` attribute argument.
+/// - Types specified via `#[delegate(as_value = Type)]` must be zero-sized
+///   unit structs.
+/// - Structs and enum variants with multiple fields (and traits derived on
+///   struct fields) cannot delegate methods returning `Self` and associated
+///   functions without receiver, as they cannot be constructed from the
+///   delegated field only.
+/// - Delegates marked with `#[delegate(deref)]` must be held in a `Box`,
+///   `Rc`, `Arc`, `&T` or `&mut T`, cannot be combined with the `pin`
+///   argument, and support owned and `&mut self` receivers only behind `Box`
+///   (or `&mut T` for the latter one).
+/// - Delegates obtained via `#[delegate(via = path -> Type)]` accessors
+///   cannot be combined with the `pin` argument, and cannot delegate
+///   methods returning `Self` and associated functions without receiver.
+/// - Delegates marked with `#[delegate(lock)]` cannot be combined with the
+///   `pin` argument, and cannot delegate `&self` methods returning borrows of
+///   the delegate, `impl Trait`, or having `Self` arguments or named receiver
+///   lifetimes.
+/// - Inherent methods listed in `#[delegate(methods { .. })]` must have a
+///   `self`, `&self` or `&mut self` receiver and identifier patterns of
+///   arguments, and cannot have `Self` arguments or return `Self`.
+/// - Supertraits of external traits (and their associated types, declared with
+///   the `#[delegate(supertrait = path)]` attribute argument) require the
+///   wrapper types of the definitions (named
+///   `__delegate_{Definition}__Wrapper`) to implement the supertraits too, as
+///   done in the `delegation::ext` module.
+/// - `Self` type is limited to be used as a whole return type of methods, and
+///   in `Self`, `&Self` or `&mut Self` arguments of methods with untyped
+///   receivers (except `async` ones and ones returning `impl Trait`) of
+///   non-external traits.
 ///
 /// [rust-lang/rust#87803]: https://github.com/rust-lang/rust/issues/87803
 #[proc_macro_attribute]
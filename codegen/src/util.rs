@@ -1,13 +1,18 @@
 //! Utilities for code generation.
 
+use proc_macro2::TokenStream;
+use quote::quote;
 #[cfg(doc)]
-use syn::{Generics, WhereClause, WherePredicate, parse::Parse};
+use syn::{Generics, Type, WhereClause, WherePredicate, parse::Parse};
 use syn::{
     parse::{ParseStream, discouraged::Speculative as _},
     punctuated::Punctuated,
     token,
+    visit::Visit,
 };
 
+use crate::MacroPath;
+
 /// Extension of [`Generics`] for code generation.
 pub(crate) trait GenericsExt: Sized {
     /// Merges two sets of [`Generics`] and returns the resulting [`Generics`].
@@ -82,3 +87,118 @@ impl WhereClauseExt for syn::WhereClause {
         Ok(Some(Self { where_token, predicates }))
     }
 }
+
+/// Extension of [`Type`] for code generation.
+pub(crate) trait TypeExt {
+    /// Returns an expression comparing the provided values of this [`Type`] in
+    /// `const` context.
+    ///
+    /// String slices are compared bytewise, while any other [`Type`] is
+    /// compared with the `==` operator, so should be one of the
+    /// [`TypeExt::is_const_comparable()`].
+    fn const_eq(
+        &self,
+        lhs: &TokenStream,
+        rhs: &TokenStream,
+        macro_path: &MacroPath,
+    ) -> TokenStream;
+
+    /// Indicates whether values of this [`Type`] can be compared in `const`
+    /// context, being an integer, [`bool`], [`char`] or `&str`.
+    fn is_const_comparable(&self) -> bool;
+
+    /// Indicates whether this [`Type`] is `Self`, `&Self` or `&mut Self`.
+    fn is_self(&self) -> bool;
+
+    /// Checks whether this [`Type`] contains any references or lifetimes
+    /// (including the elided ones).
+    fn contains_lifetimes(&self) -> bool;
+}
+
+impl TypeExt for syn::Type {
+    fn const_eq(
+        &self,
+        lhs: &TokenStream,
+        rhs: &TokenStream,
+        macro_path: &MacroPath,
+    ) -> TokenStream {
+        let is_str = matches!(
+            self,
+            Self::Reference(r) if matches!(
+                r.elem.as_ref(),
+                Self::Path(p) if p.qself.is_none() && p.path.is_ident("str"),
+            ),
+        );
+
+        if is_str {
+            quote! { #macro_path::str_eq(#lhs, #rhs) }
+        } else {
+            quote! { #lhs == #rhs }
+        }
+    }
+
+    fn is_const_comparable(&self) -> bool {
+        const PRIMITIVES: [&str; 14] = [
+            "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8",
+            "u16", "u32", "u64", "u128", "usize",
+        ];
+
+        if let Self::Group(syn::TypeGroup { elem, .. })
+        | Self::Paren(syn::TypeParen { elem, .. }) = self
+        {
+            return elem.is_const_comparable();
+        }
+        if let Self::Reference(r) = self {
+            return r.mutability.is_none()
+                && matches!(
+                    r.elem.as_ref(),
+                    Self::Path(syn::TypePath { qself: None, path })
+                        if path.is_ident("str"),
+                );
+        }
+        matches!(
+            self,
+            Self::Path(syn::TypePath { qself: None, path })
+                if path
+                    .get_ident()
+                    .is_some_and(|i| PRIMITIVES.iter().any(|p| i == p)),
+        )
+    }
+
+    fn is_self(&self) -> bool {
+        let is_self = |t: &Self| {
+            matches!(
+                t,
+                Self::Path(syn::TypePath { qself: None, path })
+                    if path.is_ident("Self"),
+            )
+        };
+
+        let Self::Reference(r) = self else {
+            return is_self(self);
+        };
+        is_self(&r.elem)
+    }
+
+    fn contains_lifetimes(&self) -> bool {
+        /// Visitor checking whether a [`Type`] contains any lifetimes.
+        struct FindLifetimes {
+            /// Indicator whether any lifetime has been found.
+            found: bool,
+        }
+
+        impl<'ast> Visit<'ast> for FindLifetimes {
+            fn visit_lifetime(&mut self, _: &'ast syn::Lifetime) {
+                self.found = true;
+            }
+
+            fn visit_type_reference(&mut self, _: &'ast syn::TypeReference) {
+                self.found = true;
+            }
+        }
+
+        let mut visitor = FindLifetimes { found: false };
+        visitor.visit_type(self);
+        visitor.found
+    }
+}
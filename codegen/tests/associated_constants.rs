@@ -0,0 +1,145 @@
+use delegation::delegate;
+
+#[delegate]
+trait Protocol {
+    #[delegate(accessor)]
+    const KIND: &'static str = "any";
+
+    #[delegate(accessor = version_of)]
+    const VERSION: u8 = 0;
+
+    const NAMESPACE: &'static str;
+
+    const MAX_FRAME: usize = 1024;
+
+    #[delegate(accessor)]
+    const MAGIC: &'static [u8] = b"";
+
+    #[delegate(accessor = limit_of)]
+    const LIMIT: Option<u32> = None;
+
+    fn frame(&self) -> usize;
+}
+
+struct Http;
+
+impl Protocol for Http {
+    const KIND: &'static str = "http";
+    const VERSION: u8 = 2;
+    const NAMESPACE: &'static str = "net";
+    const MAGIC: &'static [u8] = b"HTTP";
+    const LIMIT: Option<u32> = Some(100);
+
+    fn frame(&self) -> usize {
+        Self::MAX_FRAME
+    }
+}
+
+struct Ws;
+
+impl Protocol for Ws {
+    const KIND: &'static str = "ws";
+    const VERSION: u8 = 13;
+    const NAMESPACE: &'static str = "net";
+    const MAGIC: &'static [u8] = b"\x81";
+
+    fn frame(&self) -> usize {
+        Self::MAX_FRAME / 2
+    }
+}
+
+#[delegate(derive(Protocol))]
+enum AnyProtocol {
+    Http(Http),
+    Ws { inner: Ws },
+}
+
+#[delegate(derive(Protocol))]
+struct Wrapped(AnyProtocol);
+
+#[delegate(derive(Protocol))]
+enum Generic<P: Protocol + 'static> {
+    Inner(P),
+    Http(Http),
+}
+
+trait Limits {
+    const MAX: u32;
+
+    fn max(&self) -> u32 {
+        Self::MAX
+    }
+}
+
+impl Limits for u8 {
+    const MAX: u32 = 255;
+}
+
+impl Limits for i8 {
+    const MAX: u32 = 255;
+}
+
+#[delegate(as = Limits)]
+trait LimitsDef {
+    const MAX: u32;
+
+    fn max(&self) -> u32;
+}
+
+#[delegate(derive(Limits as LimitsDef))]
+enum Byte {
+    Unsigned(u8),
+    Signed(i8),
+}
+
+#[test]
+fn accessor_dispatches_per_variant() {
+    let http = AnyProtocol::Http(Http);
+    let ws = AnyProtocol::Ws { inner: Ws };
+
+    assert_eq!(http.kind(), "http");
+    assert_eq!(ws.kind(), "ws");
+    assert_eq!(http.version_of(), 2);
+    assert_eq!(ws.version_of(), 13);
+    assert_eq!(Http.kind(), "http");
+    assert_eq!(Wrapped(ws).kind(), "ws");
+}
+
+#[test]
+fn accessor_dispatches_not_comparable_constants() {
+    let http = AnyProtocol::Http(Http);
+    let ws = Wrapped(AnyProtocol::Ws { inner: Ws });
+
+    assert_eq!(http.magic(), b"HTTP");
+    assert_eq!(ws.magic(), b"\x81");
+    assert_eq!(http.limit_of(), Some(100));
+    assert_eq!(ws.limit_of(), None);
+    assert_eq!(AnyProtocol::MAGIC, b"");
+    assert_eq!(Wrapped::LIMIT, None);
+}
+
+#[test]
+fn accessor_constant_is_not_delegated() {
+    assert_eq!(AnyProtocol::KIND, "any");
+    assert_eq!(AnyProtocol::VERSION, 0);
+    assert_eq!(Wrapped::KIND, "any");
+    assert_eq!(<Generic<Ws>>::KIND, "any");
+}
+
+#[test]
+fn shared_constant_is_resolved() {
+    assert_eq!(AnyProtocol::NAMESPACE, "net");
+    assert_eq!(AnyProtocol::MAX_FRAME, 1024);
+    assert_eq!(Wrapped::NAMESPACE, "net");
+    assert_eq!(<Generic<Ws>>::NAMESPACE, "net");
+    assert_eq!(Generic::Inner(Ws).kind(), "ws");
+    assert_eq!(Generic::<Ws>::Http(Http).kind(), "http");
+    assert_eq!(AnyProtocol::Ws { inner: Ws }.frame(), 512);
+}
+
+#[test]
+fn external_trait_constant_is_resolved() {
+    assert_eq!(Byte::MAX, 255);
+    assert_eq!(Byte::Unsigned(1).max(), 255);
+    assert_eq!(Byte::Signed(-1).max(), 255);
+}
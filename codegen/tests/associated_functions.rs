@@ -0,0 +1,109 @@
+use delegation::delegate;
+
+#[delegate]
+trait Storage {
+    fn new() -> Self;
+
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn name() -> &'static str;
+
+    fn capacity(&self) -> usize;
+}
+
+#[derive(Debug, PartialEq)]
+struct Memory(Vec<u8>);
+
+impl Storage for Memory {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    fn name() -> &'static str {
+        "memory"
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Disk {
+    capacity: usize,
+}
+
+impl Storage for Disk {
+    fn new() -> Self {
+        Self { capacity: 0 }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self { capacity }
+    }
+
+    fn name() -> &'static str {
+        "disk"
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[delegate(derive(Storage))]
+#[derive(Debug, PartialEq)]
+enum AnyStorage {
+    Memory(Memory),
+    #[delegate(default)]
+    Disk {
+        disk: Disk,
+    },
+}
+
+#[delegate(derive(Storage))]
+#[derive(Debug, PartialEq)]
+struct Named(AnyStorage);
+
+#[test]
+fn delegates_to_default_variant() {
+    assert_eq!(AnyStorage::name(), "disk");
+    assert_eq!(AnyStorage::new(), AnyStorage::Disk { disk: Disk::new() });
+
+    let storage = AnyStorage::with_capacity(8);
+    assert_eq!(storage, AnyStorage::Disk { disk: Disk { capacity: 8 } });
+    assert_eq!(storage.capacity(), 8);
+
+    let memory = AnyStorage::Memory(Memory(Vec::with_capacity(4)));
+    assert!(memory.capacity() >= 4);
+}
+
+#[test]
+fn delegates_through_structs() {
+    assert_eq!(Named::name(), "disk");
+    assert_eq!(Named::with_capacity(2).capacity(), 2);
+    assert_eq!(Named::new(), Named(AnyStorage::Disk { disk: Disk::new() }));
+}
+
+#[delegate(as = Default)]
+trait DefaultDef {
+    fn default() -> Self;
+}
+
+#[delegate(derive(Default as DefaultDef))]
+#[derive(Debug, PartialEq)]
+enum Value {
+    Int(i32),
+    #[delegate(default)]
+    Text(String),
+}
+
+#[test]
+fn delegates_external_traits() {
+    assert_eq!(Value::default(), Value::Text(String::new()));
+    assert_ne!(Value::default(), Value::Int(0));
+}
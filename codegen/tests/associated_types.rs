@@ -0,0 +1,144 @@
+use delegation::delegate;
+
+#[delegate]
+trait Parser {
+    type Output;
+
+    fn parse(&self, input: &str) -> Self::Output;
+
+    fn parse_all(&mut self, inputs: Vec<&str>)
+    -> Vec<<Self as Parser>::Output>;
+
+    fn into_default(self) -> Option<Self::Output>;
+
+    fn parse_map<T, F>(&self, input: &str, f: F) -> T
+    where
+        F: FnOnce(Self::Output) -> T,
+    {
+        f(self.parse(input))
+    }
+}
+
+struct Number;
+
+impl Parser for Number {
+    type Output = i64;
+
+    fn parse(&self, input: &str) -> i64 {
+        input.parse().unwrap_or_default()
+    }
+
+    fn parse_all(&mut self, inputs: Vec<&str>) -> Vec<i64> {
+        inputs.into_iter().map(|i| self.parse(i)).collect()
+    }
+
+    fn into_default(self) -> Option<i64> {
+        Some(0)
+    }
+}
+
+struct Length;
+
+impl Parser for Length {
+    type Output = i64;
+
+    fn parse(&self, input: &str) -> i64 {
+        input.len().try_into().unwrap_or_default()
+    }
+
+    fn parse_all(&mut self, inputs: Vec<&str>) -> Vec<i64> {
+        inputs.into_iter().map(|i| self.parse(i)).collect()
+    }
+
+    fn into_default(self) -> Option<i64> {
+        None
+    }
+}
+
+#[delegate(derive(Parser))]
+enum AnyParser {
+    Number(Number),
+    Length { parser: Length },
+}
+
+#[delegate(derive(Parser))]
+struct Wrapped(AnyParser);
+
+#[delegate(as = Iterator)]
+trait IteratorDef {
+    type Item;
+
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[delegate(derive(Iterator as IteratorDef))]
+enum Digits {
+    Bytes(std::vec::IntoIter<u8>),
+    Repeat(std::iter::RepeatN<u8>),
+}
+
+mod public {
+    use delegation::delegate;
+
+    #[delegate]
+    pub trait Source {
+        type Item;
+
+        fn get(&self) -> Self::Item;
+    }
+
+    pub struct Fixed(pub u8);
+
+    impl Source for Fixed {
+        type Item = u8;
+
+        fn get(&self) -> u8 {
+            self.0
+        }
+    }
+
+    #[delegate(derive(Source))]
+    pub enum AnySource {
+        First(Fixed),
+        Second(Fixed),
+    }
+}
+
+#[test]
+fn enum_resolves_associated_type() {
+    let mut parser = AnyParser::Number(Number);
+    let out: <AnyParser as Parser>::Output = parser.parse("42");
+    assert_eq!(out, 42);
+    assert_eq!(parser.parse_all(vec!["1", "2"]), vec![1, 2]);
+    assert_eq!(parser.parse_map("42", |n| n * 2), 84);
+    assert_eq!(parser.into_default(), Some(0));
+
+    let mut parser = AnyParser::Length { parser: Length };
+    assert_eq!(parser.parse("42"), 2);
+    assert_eq!(parser.parse_all(vec!["1", "22"]), vec![1, 2]);
+    assert_eq!(parser.into_default(), None);
+}
+
+#[test]
+fn newtype_resolves_associated_type() {
+    let parser = Wrapped(AnyParser::Length { parser: Length });
+    let out: <Wrapped as Parser>::Output = parser.parse("abc");
+    assert_eq!(out, 3);
+}
+
+#[test]
+fn public_trait_resolves_associated_type() {
+    use public::Source as _;
+
+    assert_eq!(public::AnySource::First(public::Fixed(1)).get(), 1);
+    assert_eq!(public::AnySource::Second(public::Fixed(2)).get(), 2);
+}
+
+#[test]
+fn external_trait_resolves_associated_type() {
+    let digits = Digits::Bytes(vec![1, 2, 3].into_iter());
+    assert_eq!(digits.collect::<Vec<u8>>(), [1, 2, 3]);
+
+    let digits = Digits::Repeat(std::iter::repeat_n(7, 2));
+    assert_eq!(digits.collect::<Vec<u8>>(), [7, 7]);
+}
@@ -0,0 +1,147 @@
+use delegation::delegate;
+
+#[delegate]
+trait Same {
+    fn same_as(&self, other: &Self) -> bool {
+        _ = other;
+        false
+    }
+}
+
+#[delegate]
+trait Merge {
+    #[delegate(mismatch = merge_mismatch)]
+    fn merge(&mut self, other: Self);
+
+    fn merged(self, other: Self) -> String
+    where
+        Self: Sized,
+    {
+        _ = other;
+        "different".into()
+    }
+
+    fn len(&self) -> usize;
+}
+
+#[delegate]
+trait Distance<T: From<f32>> {
+    #[delegate(mismatch = infinite_distance)]
+    fn distance(&self, other: &Self) -> T;
+}
+
+fn infinite_distance<S: ?Sized, T: From<f32>>(_: &S, _: &S) -> T {
+    f32::INFINITY.into()
+}
+
+fn merge_mismatch<T: Merge>(this: &mut T, other: T) {
+    *this = other;
+}
+
+#[derive(Debug)]
+struct Numbers(Vec<i32>);
+
+impl Same for Numbers {
+    fn same_as(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Merge for Numbers {
+    fn merge(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+
+    fn merged(mut self, other: Self) -> String {
+        self.merge(other);
+        format!("{:?}", self.0)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Distance<f64> for Numbers {
+    #[expect(clippy::cast_precision_loss, reason = "test values are small")]
+    fn distance(&self, other: &Self) -> f64 {
+        self.0.len().abs_diff(other.0.len()) as f64
+    }
+}
+
+#[derive(Debug)]
+struct Text(String);
+
+impl Same for Text {
+    fn same_as(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Merge for Text {
+    fn merge(&mut self, other: Self) {
+        self.0.push_str(&other.0);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Distance<f64> for Text {
+    #[expect(clippy::cast_precision_loss, reason = "test values are small")]
+    fn distance(&self, other: &Self) -> f64 {
+        self.0.len().abs_diff(other.0.len()) as f64
+    }
+}
+
+#[delegate(derive(Same, Merge, Distance<f64>))]
+#[derive(Debug)]
+enum Value {
+    Numbers(Numbers),
+    Text(Text),
+}
+
+#[delegate(derive(Same, Merge))]
+#[derive(Debug)]
+struct Named(Value);
+
+#[test]
+fn delegates_on_same_variants() {
+    let nums = Value::Numbers(Numbers(vec![1, 2]));
+    assert!(nums.same_as(&Value::Numbers(Numbers(vec![1, 2]))));
+    assert!(!nums.same_as(&Value::Numbers(Numbers(vec![3]))));
+
+    let mut text = Value::Text(Text("foo".into()));
+    text.merge(Value::Text(Text("bar".into())));
+    assert_eq!(text.len(), 6);
+    assert!((text.distance(&Value::Text(Text("a".into()))) - 5.0).abs() < 0.1);
+
+    let merged = nums.merged(Value::Numbers(Numbers(vec![3])));
+    assert_eq!(merged, "[1, 2, 3]");
+}
+
+#[test]
+fn falls_back_on_different_variants() {
+    let nums = Value::Numbers(Numbers(vec![1, 2]));
+    let text = Value::Text(Text("foo".into()));
+    assert!(!nums.same_as(&text));
+    assert!(!text.same_as(&nums));
+    assert!(nums.distance(&text).is_infinite());
+
+    assert_eq!(nums.merged(text), "different");
+
+    let mut text = Value::Text(Text("foo".into()));
+    text.merge(Value::Numbers(Numbers(vec![1])));
+    assert_eq!(text.len(), 1);
+}
+
+#[test]
+fn delegates_through_structs() {
+    let mut named = Named(Value::Text(Text("foo".into())));
+    assert!(named.same_as(&Named(Value::Text(Text("foo".into())))));
+    assert!(!named.same_as(&Named(Value::Numbers(Numbers(vec![])))));
+
+    named.merge(Named(Value::Text(Text("bar".into()))));
+    assert_eq!(named.len(), 6);
+}
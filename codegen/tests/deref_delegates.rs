@@ -0,0 +1,124 @@
+use std::{rc::Rc, sync::Arc};
+
+use delegation::delegate;
+
+#[delegate]
+trait Shape {
+    fn name(&self) -> String;
+}
+
+#[delegate]
+trait Scale {
+    fn scale(&mut self, factor: u32);
+}
+
+#[delegate]
+trait Consume {
+    fn into_name(self) -> String;
+}
+
+#[delegate]
+trait Grow {
+    fn grown(self) -> Self;
+
+    fn origin() -> Self;
+}
+
+#[derive(Clone, Default)]
+struct Square(u32);
+
+impl Shape for Square {
+    fn name(&self) -> String {
+        format!("square {}", self.0)
+    }
+}
+
+impl Scale for Square {
+    fn scale(&mut self, factor: u32) {
+        self.0 *= factor;
+    }
+}
+
+impl Consume for Square {
+    fn into_name(self) -> String {
+        format!("owned square {}", self.0)
+    }
+}
+
+impl Grow for Square {
+    fn grown(self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    fn origin() -> Self {
+        Self(0)
+    }
+}
+
+#[delegate(derive(Shape, Scale, Consume, Grow))]
+enum Boxed {
+    #[delegate(deref, default)]
+    Square(Box<Square>),
+}
+
+#[delegate(derive(Shape))]
+enum Shared<'a> {
+    #[delegate(deref)]
+    Rc(Rc<Square>),
+    #[delegate(deref)]
+    Arc(Arc<Square>),
+    #[delegate(deref)]
+    Ref(&'a Square),
+}
+
+#[delegate(derive(Shape, Scale))]
+enum Borrowed<'a> {
+    #[delegate(deref)]
+    Mut(&'a mut Square),
+}
+
+#[delegate(derive(Shape, Scale, Consume))]
+struct Handle {
+    #[delegate(to, deref)]
+    shape: Box<Square>,
+    id: u8,
+}
+
+#[test]
+fn delegates_through_box() {
+    let mut shape = Boxed::Square(Box::new(Square(2)));
+    shape.scale(3);
+    assert_eq!(shape.name(), "square 6");
+
+    let shape = shape.grown();
+    assert!(matches!(&shape, Boxed::Square(sq) if sq.0 == 7));
+    assert_eq!(shape.into_name(), "owned square 7");
+
+    assert_eq!(Boxed::origin().name(), "square 0");
+}
+
+#[test]
+fn delegates_through_shared_pointers() {
+    let square = Square(4);
+    assert_eq!(Shared::Rc(Rc::new(square.clone())).name(), "square 4");
+    assert_eq!(Shared::Arc(Arc::new(square.clone())).name(), "square 4");
+    assert_eq!(Shared::Ref(&square).name(), "square 4");
+}
+
+#[test]
+fn delegates_through_mutable_reference() {
+    let mut square = Square(5);
+    let mut shape = Borrowed::Mut(&mut square);
+    shape.scale(2);
+    assert_eq!(shape.name(), "square 10");
+    assert_eq!(square.0, 10);
+}
+
+#[test]
+fn delegates_through_struct_field() {
+    let mut handle = Handle { shape: Box::new(Square(1)), id: 3 };
+    handle.scale(5);
+    assert_eq!(handle.name(), "square 5");
+    assert_eq!(handle.id, 3);
+    assert_eq!(handle.into_name(), "owned square 5");
+}
@@ -0,0 +1,98 @@
+use std::{error::Error, fmt, io, num};
+
+use delegation::{delegate, ext};
+
+#[derive(Debug)]
+struct Cause;
+
+impl fmt::Display for Cause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("root cause")
+    }
+}
+
+impl Error for Cause {}
+
+#[derive(Debug)]
+struct Failed(Cause);
+
+impl fmt::Display for Failed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation failed")
+    }
+}
+
+impl Error for Failed {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[delegate(derive(
+    Error as ext::error::Error,
+    fmt::Display as ext::fmt::Display,
+    fmt::Debug as ext::fmt::Debug,
+))]
+enum StorageError {
+    Io(io::Error),
+    Failed(Failed),
+}
+
+#[delegate(derive(
+    Error as ext::error::Error,
+    fmt::Display as ext::fmt::Display,
+    fmt::Debug as ext::fmt::Debug,
+))]
+enum ParseError {
+    Int(num::ParseIntError),
+    Float(num::ParseFloatError),
+}
+
+#[delegate(derive(
+    Error as ext::error::Error,
+    fmt::Display as ext::fmt::Display,
+    fmt::Debug as ext::fmt::Debug,
+))]
+enum AppError {
+    Storage(StorageError),
+    Parse(ParseError),
+}
+
+fn parse(input: &str) -> Result<u8, Box<dyn Error>> {
+    let n =
+        input.parse::<u8>().map_err(|e| AppError::Parse(ParseError::Int(e)))?;
+    Ok(n)
+}
+
+#[test]
+fn delegates_display_and_debug() {
+    let err = AppError::Storage(StorageError::Io(io::Error::other("disk")));
+    assert_eq!(err.to_string(), "disk");
+    assert_eq!(format!("{err:?}"), format!("{:?}", io::Error::other("disk")),);
+
+    let err =
+        AppError::Parse(ParseError::Float("x".parse::<f64>().unwrap_err()));
+    assert_eq!(err.to_string(), "invalid float literal");
+}
+
+#[test]
+fn delegates_source() {
+    let err = AppError::Storage(StorageError::Failed(Failed(Cause)));
+    assert_eq!(err.to_string(), "operation failed");
+
+    let source = err.source().unwrap();
+    assert!(source.is::<Cause>());
+    assert_eq!(source.to_string(), "root cause");
+
+    let err = AppError::Parse(ParseError::Int("x".parse::<u8>().unwrap_err()));
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn converts_into_boxed_error() {
+    assert_eq!(parse("7").unwrap(), 7);
+
+    let err = parse("x").unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit found in string");
+    assert!(err.downcast_ref::<AppError>().is_some());
+}
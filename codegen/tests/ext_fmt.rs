@@ -0,0 +1,64 @@
+use core::fmt;
+
+use delegation::{delegate, ext};
+
+#[delegate(derive(
+    fmt::Display as ext::fmt::Display,
+    fmt::Debug as ext::fmt::Debug,
+    fmt::Binary as ext::fmt::Binary,
+    fmt::Octal as ext::fmt::Octal,
+    fmt::LowerHex as ext::fmt::LowerHex,
+    fmt::UpperHex as ext::fmt::UpperHex,
+))]
+enum Number {
+    Small(u8),
+    Big(u64),
+}
+
+#[delegate(derive(
+    fmt::LowerExp as ext::fmt::LowerExp,
+    fmt::UpperExp as ext::fmt::UpperExp,
+))]
+struct Float(f64);
+
+#[delegate(derive(fmt::Pointer as ext::fmt::Pointer))]
+struct Ptr<'a>(&'a u8);
+
+#[delegate(derive(fmt::Write as ext::fmt::Write))]
+struct Buffer(String);
+
+#[test]
+fn delegates_formatting_traits() {
+    let small = Number::Small(10);
+    let big = Number::Big(255);
+
+    assert_eq!(small.to_string(), "10");
+    assert_eq!(format!("{big:?}"), "255");
+    assert_eq!(format!("{small:b}"), "1010");
+    assert_eq!(format!("{small:o}"), "12");
+    assert_eq!(format!("{big:x}"), "ff");
+    assert_eq!(format!("{big:#X}"), "0xFF");
+}
+
+#[test]
+fn delegates_exponent_traits() {
+    assert_eq!(format!("{:e}", Float(1500.0)), "1.5e3");
+    assert_eq!(format!("{:E}", Float(1500.0)), "1.5E3");
+}
+
+#[test]
+fn delegates_pointer() {
+    let value = 1;
+    assert_eq!(format!("{:p}", Ptr(&value)), format!("{:p}", &value));
+}
+
+#[test]
+fn delegates_write() {
+    use fmt::Write as _;
+
+    let mut buf = Buffer(String::new());
+    buf.write_str("a").unwrap();
+    buf.write_char('b').unwrap();
+    write!(buf, "{}", 1).unwrap();
+    assert_eq!(buf.0, "ab1");
+}
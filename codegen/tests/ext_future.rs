@@ -0,0 +1,68 @@
+use std::{
+    future::{self, Future},
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+
+use delegation::{delegate, ext};
+
+/// Polls the provided future to completion on the current thread.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+/// Future being pending for the provided number of polls.
+struct Countdown(u8);
+
+impl Future for Countdown {
+    type Output = u8;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+        if self.0 == 0 {
+            return Poll::Ready(0);
+        }
+        self.0 -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[delegate(derive(Future as ext::future::Future), pin)]
+enum Branch<F: Future<Output = u8> + 'static> {
+    Ready(future::Ready<u8>),
+    Countdown(Countdown),
+    Async(F),
+}
+
+fn branch(n: u8) -> Branch<impl Future<Output = u8>> {
+    match n {
+        0 => Branch::Ready(future::ready(10)),
+        1 => Branch::Countdown(Countdown(3)),
+        _ => Branch::Async(async move { Countdown(n).await + n }),
+    }
+}
+
+#[test]
+fn delegates_future() {
+    assert_eq!(block_on(branch(0)), 10);
+    assert_eq!(block_on(branch(1)), 0);
+    assert_eq!(block_on(branch(5)), 5);
+}
+
+#[test]
+fn delegates_future_in_async_code() {
+    let out = block_on(async {
+        let mut sum = 0;
+        for n in 0..4 {
+            sum += branch(n).await;
+        }
+        sum
+    });
+    assert_eq!(out, 10 + 2 + 3);
+}
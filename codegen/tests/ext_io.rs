@@ -0,0 +1,89 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{
+        self, BufRead as _, Cursor, Read as _, Seek as _, SeekFrom, Write as _,
+    },
+    process,
+};
+
+use delegation::{delegate, ext};
+
+#[delegate(derive(
+    io::Read as ext::io::Read,
+    io::Write as ext::io::Write,
+    io::Seek as ext::io::Seek,
+))]
+enum Stream {
+    Growable(Cursor<Vec<u8>>),
+    Fixed(Cursor<Box<[u8]>>),
+    File(File),
+}
+
+#[delegate(derive(
+    io::Read as ext::io::Read,
+    io::BufRead as ext::io::BufRead,
+))]
+struct Lines(Cursor<&'static [u8]>);
+
+#[test]
+fn delegates_read_write_seek() {
+    let path = env::temp_dir()
+        .join(format!("delegation_ext_io_{}.txt", process::id()));
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+
+    for mut stream in [
+        Stream::Growable(Cursor::new(Vec::new())),
+        Stream::Fixed(Cursor::new(vec![0; 13].into_boxed_slice())),
+        Stream::File(file),
+    ] {
+        stream.write_all(b"hello, world").unwrap();
+        write!(stream, "!").unwrap();
+        stream.flush().unwrap();
+        assert_eq!(stream.stream_position().unwrap(), 13);
+
+        stream.rewind().unwrap();
+        let mut buf = [0; 5];
+        stream.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+
+        stream.seek_relative(2).unwrap();
+        let mut rest = String::new();
+        stream.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "world!");
+
+        assert_eq!(stream.seek(SeekFrom::End(-1)).unwrap(), 12);
+        let mut end = Vec::new();
+        stream.read_to_end(&mut end).unwrap();
+        assert_eq!(end, b"!");
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn delegates_buf_read() {
+    let mut lines = Lines(Cursor::new(b"first\nsecond\nthird"));
+
+    assert_eq!(lines.fill_buf().unwrap(), b"first\nsecond\nthird");
+    lines.consume(6);
+
+    let mut line = String::new();
+    lines.read_line(&mut line).unwrap();
+    assert_eq!(line, "second\n");
+
+    let mut buf = Vec::new();
+    lines.read_until(b'r', &mut buf).unwrap();
+    assert_eq!(buf, b"thir");
+
+    let mut rest = [0; 1];
+    lines.read_exact(&mut rest).unwrap();
+    assert_eq!(&rest, b"d");
+    assert_eq!(lines.skip_until(b'x').unwrap(), 0);
+}
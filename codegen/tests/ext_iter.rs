@@ -0,0 +1,66 @@
+use std::{collections::btree_set, iter, ops, vec};
+
+use delegation::{delegate, ext};
+
+#[delegate(derive(
+    Iterator as ext::iter::Iterator,
+    DoubleEndedIterator as ext::iter::DoubleEndedIterator,
+    ExactSizeIterator as ext::iter::ExactSizeIterator,
+))]
+enum Numbers {
+    Range(ops::Range<u32>),
+    Vec(vec::IntoIter<u32>),
+    Set(btree_set::IntoIter<u32>),
+}
+
+#[delegate(derive(Iterator as ext::iter::Iterator))]
+struct Evens(iter::StepBy<ops::RangeFrom<u32>>);
+
+fn all() -> [Numbers; 3] {
+    [
+        Numbers::Range(1..5),
+        Numbers::Vec(vec![1, 2, 3, 4].into_iter()),
+        Numbers::Set(
+            [4, 3, 2, 1]
+                .into_iter()
+                .collect::<btree_set::BTreeSet<_>>()
+                .into_iter(),
+        ),
+    ]
+}
+
+#[test]
+fn delegates_iterator() {
+    for mut numbers in all() {
+        assert_eq!(numbers.size_hint(), (4, Some(4)));
+        assert_eq!(numbers.next(), Some(1));
+        assert_eq!(numbers.nth(1), Some(3));
+        assert_eq!(
+            numbers.fold(Vec::new(), |mut acc, n| {
+                acc.push(n);
+                acc
+            }),
+            [4]
+        );
+    }
+
+    let evens = Evens((0..).step_by(2));
+    assert_eq!(evens.take(3).collect::<Vec<_>>(), [0, 2, 4]);
+}
+
+#[test]
+fn delegates_double_ended_iterator() {
+    for mut numbers in all() {
+        assert_eq!(numbers.next_back(), Some(4));
+        assert_eq!(numbers.rev().collect::<Vec<_>>(), [3, 2, 1]);
+    }
+}
+
+#[test]
+fn delegates_exact_size_iterator() {
+    for mut numbers in all() {
+        assert_eq!(numbers.len(), 4);
+        _ = numbers.next();
+        assert_eq!(numbers.len(), 3);
+    }
+}
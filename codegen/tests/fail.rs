@@ -1,4 +1,4 @@
-#[rustversion::stable(1.84)]
+#[rustversion::stable(1.86)]
 #[test]
 fn fail() {
     let t = trybuild::TestCases::new();
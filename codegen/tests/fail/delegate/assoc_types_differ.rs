@@ -0,0 +1,36 @@
+use delegation::delegate;
+
+#[delegate]
+trait Parser {
+    type Output;
+
+    fn parse(&self, input: &str) -> Self::Output;
+}
+
+struct Int;
+
+impl Parser for Int {
+    type Output = i32;
+
+    fn parse(&self, input: &str) -> i32 {
+        input.parse().unwrap_or_default()
+    }
+}
+
+struct Float;
+
+impl Parser for Float {
+    type Output = f64;
+
+    fn parse(&self, input: &str) -> f64 {
+        input.parse().unwrap_or_default()
+    }
+}
+
+#[delegate(derive(Parser))]
+enum AnyParser {
+    Int(Int),
+    Float(Float),
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+use delegation::delegate;
+
+#[delegate]
+trait Storage {
+    fn new() -> Self;
+
+    fn name() -> &'static str;
+
+    fn capacity(&self) -> usize;
+}
+
+struct Memory;
+
+impl Storage for Memory {
+    fn new() -> Self {
+        Self
+    }
+
+    fn name() -> &'static str {
+        "memory"
+    }
+
+    fn capacity(&self) -> usize {
+        0
+    }
+}
+
+struct Disk;
+
+impl Storage for Disk {
+    fn new() -> Self {
+        Self
+    }
+
+    fn name() -> &'static str {
+        "disk"
+    }
+
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+#[delegate(derive(Storage))]
+enum AnyStorage {
+    Memory(Memory),
+    Disk(Disk),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use delegation::delegate;
+
+#[delegate]
+trait Merge {
+    #[delegate(mismatch = drop_both)]
+    fn merge(self: Box<Self>, other: Self) -> Box<Self>;
+}
+
+fn drop_both<T>(this: Box<T>, _: T) -> Box<T> {
+    this
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use delegation::delegate;
+
+#[delegate]
+trait Same {
+    fn same_as(&self, other: &Self) -> bool;
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use delegation::delegate;
+
+struct User(String);
+
+impl User {
+    fn first(&self) -> &str {
+        &self.0
+    }
+}
+
+#[delegate(methods {
+    fn first(&self) -> &str;
+})]
+enum AnyUser {
+    Owned(User),
+    #[delegate(lock)]
+    Shared(std::sync::Mutex<User>),
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use std::sync::Mutex;
+
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> &str;
+}
+
+struct User(String);
+
+impl Named for User {
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+#[delegate(derive(Named))]
+struct Shared(#[delegate(lock)] Mutex<User>);
+
+fn main() {
+    let shared = Shared(Mutex::new(User("Ferris".into())));
+    _ = shared.name();
+}
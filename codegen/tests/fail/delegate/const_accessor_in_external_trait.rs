@@ -0,0 +1,13 @@
+use delegation::delegate;
+
+pub trait Channel {
+    const ID: usize;
+}
+
+#[delegate(as = Channel)]
+trait ChannelDef {
+    #[delegate(accessor)]
+    const ID: usize;
+}
+
+fn main() {}
@@ -2,6 +2,7 @@ use delegation::delegate;
 
 #[delegate]
 trait Channel {
+    #[delegate(accessor)]
     const ID: usize;
 }
 
@@ -0,0 +1,12 @@
+use delegation::delegate;
+
+trait Limits {
+    const LIMIT: Option<u32>;
+}
+
+#[delegate(as = Limits)]
+trait LimitsDef {
+    const LIMIT: Option<u32>;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use delegation::delegate;
+
+#[delegate]
+trait Protocol {
+    const MAGIC: &'static [u8];
+}
+
+fn main() {}
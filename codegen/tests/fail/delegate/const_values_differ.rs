@@ -0,0 +1,26 @@
+use delegation::delegate;
+
+#[delegate]
+trait Channel {
+    const ID: usize;
+}
+
+struct Tcp;
+
+impl Channel for Tcp {
+    const ID: usize = 1;
+}
+
+struct Udp;
+
+impl Channel for Udp {
+    const ID: usize = 2;
+}
+
+#[delegate(derive(Channel))]
+enum AnyChannel {
+    Tcp(Tcp),
+    Udp(Udp),
+}
+
+fn main() {}
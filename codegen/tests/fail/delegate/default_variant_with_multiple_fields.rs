@@ -0,0 +1,13 @@
+use delegation::delegate;
+
+#[delegate]
+enum Name {
+    #[delegate(default)]
+    First {
+        #[delegate(to)]
+        first: String,
+        last: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use std::mem::ManuallyDrop;
+
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> &str;
+}
+
+struct User(String);
+
+impl Named for User {
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+#[delegate(derive(Named))]
+enum AnyUser {
+    Owned(User),
+    #[delegate(deref)]
+    Kept(ManuallyDrop<User>),
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn current(&self) -> u8;
+}
+
+impl Counter for u8 {
+    fn current(&self) -> u8 {
+        *self
+    }
+}
+
+#[delegate(derive(Counter), pin)]
+enum Boxed {
+    #[delegate(deref)]
+    Counter(Box<u8>),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> String;
+}
+
+#[delegate(derive(Named))]
+struct Session {
+    id: u64,
+    #[delegate(derive(Named))]
+    user: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use delegation::delegate;
+
+#[delegate(methods {
+    fn len(&self) -> usize;
+    fn new() -> Self;
+})]
+struct Values(Vec<u8>);
+
+fn main() {}
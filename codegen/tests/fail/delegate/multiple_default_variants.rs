@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+enum AnyStorage {
+    #[delegate(default)]
+    Memory(Vec<u8>),
+    #[delegate(default)]
+    Disk(String),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use delegation::delegate;
+
+#[delegate]
+trait Reset {
+    fn reset(&mut self);
+}
+
+struct Counter(u32);
+
+impl Reset for Counter {
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+#[delegate(derive(Reset))]
+struct Shared(#[delegate(deref)] Arc<Counter>);
+
+fn main() {
+    Shared(Arc::new(Counter(1))).reset();
+}
@@ -1,10 +0,0 @@
-// TODO: Remove once associated types are supported.
-
-use delegation::delegate;
-
-#[delegate]
-trait Parser {
-    type Buffer;
-}
-
-fn main() {}
@@ -0,0 +1,10 @@
+// TODO: Remove once generic associated types are supported.
+
+use delegation::delegate;
+
+#[delegate]
+trait Parser {
+    type Buffer<'a>;
+}
+
+fn main() {}
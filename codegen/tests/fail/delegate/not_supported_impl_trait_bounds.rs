@@ -0,0 +1,8 @@
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> impl std::fmt::Display + Send;
+}
+
+fn main() {}
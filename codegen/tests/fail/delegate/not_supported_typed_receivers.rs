@@ -0,0 +1,8 @@
+use delegation::delegate;
+
+#[delegate]
+trait Channel {
+    fn id(self: &Self) -> usize;
+}
+
+fn main() {}
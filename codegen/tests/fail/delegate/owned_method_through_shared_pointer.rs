@@ -0,0 +1,26 @@
+use std::rc::Rc;
+
+use delegation::delegate;
+
+#[delegate]
+trait Consume {
+    fn consume(self) -> String;
+}
+
+struct Cache;
+
+impl Consume for Cache {
+    fn consume(self) -> String {
+        "cache".into()
+    }
+}
+
+#[delegate(derive(Consume))]
+enum Shared {
+    #[delegate(deref)]
+    Cache(Rc<Cache>),
+}
+
+fn main() {
+    _ = Shared::Cache(Rc::new(Cache)).consume();
+}
@@ -0,0 +1,26 @@
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn current(&self) -> u8;
+}
+
+struct Unpinned(u8);
+
+impl Counter for Unpinned {
+    fn current(&self) -> u8 {
+        self.0
+    }
+}
+
+#[delegate(derive(Counter), pin)]
+enum AnyCounter {
+    First(Unpinned),
+    Second(Unpinned),
+}
+
+impl Drop for AnyCounter {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
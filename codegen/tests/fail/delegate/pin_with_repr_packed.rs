@@ -0,0 +1,18 @@
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn current(&self) -> u8;
+}
+
+impl Counter for u8 {
+    fn current(&self) -> u8 {
+        *self
+    }
+}
+
+#[delegate(derive(Counter), pin)]
+#[repr(packed)]
+struct Packed(u8);
+
+fn main() {}
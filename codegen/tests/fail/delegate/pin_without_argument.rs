@@ -0,0 +1,25 @@
+use std::pin::Pin;
+
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn bump(self: Pin<&mut Self>) -> u8;
+}
+
+struct Unpinned(u8);
+
+impl Counter for Unpinned {
+    fn bump(mut self: Pin<&mut Self>) -> u8 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[delegate(derive(Counter))]
+enum AnyCounter {
+    First(Unpinned),
+    Second(Unpinned),
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use delegation::delegate;
+
+#[delegate(as = Future)]
+trait FutureDef {
+    type Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
+
+impl<T: ?Sized> Drop for __delegate_FutureDef__Wrapper<T> {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
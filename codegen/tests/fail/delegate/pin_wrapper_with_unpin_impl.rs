@@ -0,0 +1,21 @@
+use std::{
+    future::Future,
+    marker::PhantomPinned,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use delegation::delegate;
+
+#[delegate(as = Future)]
+trait FutureDef {
+    type Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
+
+struct Pinned(PhantomPinned);
+
+impl Unpin for __delegate_FutureDef__Wrapper<Pinned> {}
+
+fn main() {}
@@ -0,0 +1,50 @@
+use delegation::{private::Either, delegate};
+
+#[delegate]
+trait Normalize {
+    fn normalized(&self) -> Self;
+}
+
+impl Normalize for String {
+    fn normalized(&self) -> Self {
+        self.trim().into()
+    }
+}
+
+#[delegate(derive(Normalize))]
+enum EitherDef {
+    Left(String),
+    Right(String),
+}
+
+impl<'a> From<&'a mut Either<String, String>> for &'a mut EitherDef {
+    fn from(t: &'a mut Either<String, String>) -> Self {
+        #[expect(unsafe_code, reason = "macro expansion")]
+        unsafe {
+            &mut *(t as *mut Either<String, String> as *mut EitherDef)
+        }
+    }
+}
+
+impl<'a> From<&'a Either<String, String>> for &'a EitherDef {
+    fn from(t: &'a Either<String, String>) -> Self {
+        #[expect(unsafe_code, reason = "macro expansion")]
+        unsafe {
+            &*(t as *const Either<String, String> as *const EitherDef)
+        }
+    }
+}
+
+impl From<Either<String, String>> for EitherDef {
+    fn from(t: Either<String, String>) -> Self {
+        match t {
+            Either::Left(t) => EitherDef::Left(t),
+            Either::Right(t) => EitherDef::Right(t),
+        }
+    }
+}
+
+#[delegate(derive(Normalize))]
+struct EitherString(#[delegate(as = EitherDef)] Either<String, String>);
+
+fn main() {}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use delegation::delegate;
+
+#[delegate]
+trait Task {
+    fn spawn(self: Arc<Self>) -> usize;
+}
+
+struct Job;
+
+impl Task for Job {
+    fn spawn(self: Arc<Self>) -> usize {
+        Arc::strong_count(&self)
+    }
+}
+
+#[delegate(derive(Task))]
+enum AnyTask {
+    Job(Job),
+}
+
+fn main() {
+    _ = Arc::new(AnyTask::Job(Job)).spawn();
+}
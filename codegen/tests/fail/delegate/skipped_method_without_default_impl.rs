@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+trait Shape {
+    fn area(&self) -> f64;
+
+    #[delegate(skip)]
+    fn describe(&self) -> String;
+}
+
+fn main() {}
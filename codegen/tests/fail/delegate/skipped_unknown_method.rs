@@ -0,0 +1,19 @@
+use delegation::delegate;
+
+#[delegate]
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+#[delegate(derive(Shape(skip(perimeter))))]
+struct Named(Square);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+struct Name {
+    #[delegate(to)]
+    first: String,
+    #[delegate(to)]
+    last: String,
+}
+
+fn main() {}
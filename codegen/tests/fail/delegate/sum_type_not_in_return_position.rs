@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+trait Merge {
+    #[delegate(sum)]
+    type Output;
+
+    fn merge(&self, other: Self::Output) -> Self::Output;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+trait Handler {
+    #[delegate(union)]
+    type Output;
+
+    fn handle(&self) -> Self::Output;
+}
+
+fn main() {}
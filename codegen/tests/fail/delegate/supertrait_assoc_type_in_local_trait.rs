@@ -0,0 +1,11 @@
+use delegation::delegate;
+
+#[delegate]
+trait Reversed: Iterator {
+    #[delegate(supertrait = Iterator)]
+    type Item;
+
+    fn last_item(&mut self) -> Option<Self::Item>;
+}
+
+fn main() {}
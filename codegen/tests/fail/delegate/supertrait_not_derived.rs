@@ -0,0 +1,34 @@
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> String;
+}
+
+#[delegate]
+trait Shape: Named {
+    fn corners(&self) -> usize;
+}
+
+struct Square;
+
+impl Named for Square {
+    fn name(&self) -> String {
+        "square".into()
+    }
+}
+
+impl Shape for Square {
+    fn corners(&self) -> usize {
+        4
+    }
+}
+
+#[delegate(derive(Shape))]
+enum AnyShape {
+    Square(Square),
+}
+
+fn main() {
+    _ = AnyShape::Square(Square).corners();
+}
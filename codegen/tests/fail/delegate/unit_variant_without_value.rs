@@ -0,0 +1,9 @@
+use delegation::delegate;
+
+#[delegate]
+enum Backend {
+    Memory(String),
+    Disabled,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use delegation::delegate;
+
+struct Noop;
+
+#[delegate]
+enum Backend {
+    Memory(String),
+    #[delegate(as_value = Noop)]
+    Disabled(String),
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn current(&self) -> u8;
+}
+
+impl Counter for u8 {
+    fn current(&self) -> u8 {
+        *self
+    }
+}
+
+#[delegate(derive(Counter), via_mut = Self::counter_mut)]
+struct Counters {
+    counters: [u8; 2],
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use delegation::delegate;
+
+mod adapter {}
+
+#[delegate]
+struct Name(#[delegate(with = adapter)] String);
+
+fn main() {}
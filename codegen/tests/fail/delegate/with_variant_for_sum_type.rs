@@ -0,0 +1,47 @@
+use delegation::delegate;
+
+#[delegate]
+trait Source {
+    #[delegate(sum)]
+    type Item;
+
+    fn first(&self) -> Self::Item;
+}
+
+struct Bytes;
+
+impl Source for Bytes {
+    type Item = u8;
+
+    fn first(&self) -> u8 {
+        0
+    }
+}
+
+struct Chars;
+
+impl Source for Chars {
+    type Item = char;
+
+    fn first(&self) -> char {
+        'a'
+    }
+}
+
+struct Empty;
+
+mod empty {
+    pub fn first(_: &super::Empty) -> bool {
+        false
+    }
+}
+
+#[delegate(derive(Source))]
+enum AnySource {
+    Bytes(Bytes),
+    Chars(Chars),
+    #[delegate(with = empty)]
+    Empty(Empty),
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(self: Box<Self>) -> String;
+}
+
+impl Named for String {
+    fn name(self: Box<Self>) -> String {
+        *self
+    }
+}
+
+struct Unnamed;
+
+mod unnamed {
+    pub fn name(_: super::Unnamed) -> String {
+        String::new()
+    }
+}
+
+#[delegate(derive(Named))]
+enum Name {
+    Named(String),
+    #[delegate(with = unnamed)]
+    Unnamed(Unnamed),
+}
+
+fn main() {}
@@ -0,0 +1,88 @@
+use delegation::delegate;
+
+#[delegate]
+trait Storage {
+    fn get(&self, key: &str) -> Option<String>;
+
+    fn set(&mut self, key: &str, value: String);
+}
+
+#[delegate]
+trait Metrics {
+    fn hits(&self) -> usize;
+
+    fn record(&mut self);
+
+    fn into_hits(self) -> usize;
+}
+
+#[derive(Default)]
+struct Memory(Vec<(String, String)>);
+
+impl Storage for Memory {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        self.0.push((key.into(), value));
+    }
+}
+
+#[derive(Default)]
+struct Counter(usize);
+
+impl Metrics for Counter {
+    fn hits(&self) -> usize {
+        self.0
+    }
+
+    fn record(&mut self) {
+        self.0 += 1;
+    }
+
+    fn into_hits(self) -> usize {
+        self.0
+    }
+}
+
+#[delegate]
+pub struct Cache {
+    id: u64,
+    #[delegate(derive(Storage))]
+    storage: Memory,
+    #[delegate(derive(Metrics))]
+    metrics: Counter,
+}
+
+#[delegate(derive(Storage))]
+struct Tracked<M: Metrics + 'static>(
+    #[delegate(to)] Memory,
+    #[delegate(derive(Metrics))] M,
+);
+
+#[test]
+fn delegates_traits_to_different_fields() {
+    let mut cache = Cache {
+        id: 1,
+        storage: Memory::default(),
+        metrics: Counter::default(),
+    };
+    cache.set("key", "value".into());
+    cache.record();
+    cache.record();
+    assert_eq!(cache.get("key").as_deref(), Some("value"));
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.id, 1);
+    assert_eq!(cache.into_hits(), 2);
+}
+
+#[test]
+fn combines_field_derives_with_delegated_field() {
+    let mut tracked = Tracked(Memory::default(), Counter(3));
+    tracked.set("key", "value".into());
+    tracked.record();
+    assert_eq!(tracked.get("key").as_deref(), Some("value"));
+    assert_eq!(tracked.hits(), 4);
+    assert_eq!(tracked.into_hits(), 4);
+}
@@ -0,0 +1,135 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use delegation::delegate;
+
+#[delegate(methods {
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize;
+    pub fn is_empty(&self) -> bool;
+    pub fn clear(&mut self);
+})]
+struct Values(Vec<u8>);
+
+#[delegate(methods {
+    fn len(&self) -> usize;
+    fn clear(&mut self);
+    fn into_keys(self) -> Vec<String>;
+    fn contains(&self, key: &str) -> bool;
+})]
+enum Storage {
+    Memory(Memory),
+    Indexed(Indexed),
+    #[delegate(lock)]
+    Shared(Mutex<Memory>),
+    #[delegate(with = disabled)]
+    Disabled(disabled::Disabled),
+}
+
+struct Memory(Vec<String>);
+
+impl Memory {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn into_keys(self) -> Vec<String> {
+        self.0
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        self.0.iter().any(|k| k == key)
+    }
+}
+
+struct Indexed(HashMap<String, usize>);
+
+impl Indexed {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn into_keys(self) -> Vec<String> {
+        let mut keys = self.0.into_keys().collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+}
+
+mod disabled {
+    pub(super) struct Disabled;
+
+    pub(super) const fn len(_: &Disabled) -> usize {
+        0
+    }
+
+    pub(super) const fn clear(_: &mut Disabled) {}
+
+    pub(super) const fn into_keys(_: Disabled) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub(super) const fn contains(_: &Disabled, _: &str) -> bool {
+        false
+    }
+}
+
+#[delegate(methods {
+    fn len(&self) -> usize;
+})]
+struct Named {
+    #[delegate(to)]
+    values: Vec<u8>,
+    name: &'static str,
+}
+
+#[test]
+fn delegates_to_struct_field() {
+    let mut values = Values(vec![1, 2, 3]);
+    assert_eq!(values.len(), 3);
+    assert!(!values.is_empty());
+    values.clear();
+    assert!(values.is_empty());
+
+    let named = Named { values: vec![1], name: "one" };
+    assert_eq!(named.len(), 1);
+    assert_eq!(named.name, "one");
+}
+
+#[test]
+fn delegates_to_all_variants() {
+    let mut memory = Storage::Memory(Memory(vec!["a".into()]));
+    assert_eq!(memory.len(), 1);
+    assert!(memory.contains("a"));
+    memory.clear();
+    assert_eq!(memory.len(), 0);
+
+    let indexed = Storage::Indexed(Indexed(HashMap::from([
+        ("b".into(), 1),
+        ("a".into(), 0),
+    ])));
+    assert!(indexed.contains("b"));
+    assert_eq!(indexed.into_keys(), ["a", "b"]);
+
+    let mut shared =
+        Storage::Shared(Mutex::new(Memory(vec!["c".into(), "d".into()])));
+    assert_eq!(shared.len(), 2);
+    shared.clear();
+    assert_eq!(shared.into_keys(), Vec::<String>::new());
+
+    let mut disabled = Storage::Disabled(disabled::Disabled);
+    disabled.clear();
+    assert_eq!(disabled.len(), 0);
+    assert!(!disabled.contains("a"));
+}
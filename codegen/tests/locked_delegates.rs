@@ -0,0 +1,116 @@
+use std::{
+    cell::RefCell,
+    sync::{Mutex, RwLock},
+};
+
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn count(&self) -> u32;
+
+    fn label(&self) -> &'static str;
+
+    fn add(&mut self, n: u32);
+
+    fn into_count(self) -> u32;
+}
+
+#[delegate]
+trait Zero {
+    fn zero() -> Self;
+}
+
+#[derive(Default)]
+struct Hits(u32);
+
+impl Counter for Hits {
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn label(&self) -> &'static str {
+        "hits"
+    }
+
+    fn add(&mut self, n: u32) {
+        self.0 += n;
+    }
+
+    fn into_count(self) -> u32 {
+        self.0
+    }
+}
+
+impl Zero for Hits {
+    fn zero() -> Self {
+        Self(0)
+    }
+}
+
+#[delegate(derive(Counter))]
+struct Shared(#[delegate(lock)] Mutex<Hits>);
+
+#[delegate(derive(Counter, Zero))]
+enum Guarded {
+    #[delegate(lock, default)]
+    Mutex(Mutex<Hits>),
+    #[delegate(lock)]
+    RwLock(RwLock<Hits>),
+    #[delegate(lock)]
+    RefCell(RefCell<Hits>),
+    Plain(Hits),
+}
+
+#[delegate(derive(Counter))]
+struct Stats {
+    #[delegate(to, lock)]
+    hits: RwLock<Hits>,
+    name: &'static str,
+}
+
+#[test]
+fn delegates_through_mutex() {
+    let mut shared = Shared(Mutex::new(Hits(1)));
+    shared.add(2);
+    assert_eq!(shared.count(), 3);
+    assert_eq!(shared.label(), "hits");
+    assert_eq!(shared.into_count(), 3);
+}
+
+#[test]
+fn delegates_through_any_lock() {
+    for mut guarded in [
+        Guarded::Mutex(Mutex::new(Hits(1))),
+        Guarded::RwLock(RwLock::new(Hits(1))),
+        Guarded::RefCell(RefCell::new(Hits(1))),
+        Guarded::Plain(Hits(1)),
+    ] {
+        guarded.add(4);
+        assert_eq!(guarded.count(), 5);
+        assert_eq!(guarded.into_count(), 5);
+    }
+
+    assert!(
+        matches!(Guarded::zero(), Guarded::Mutex(m) if m.lock().unwrap().0 == 0)
+    );
+}
+
+#[test]
+fn releases_lock_after_call() {
+    let shared = Shared(Mutex::new(Hits(7)));
+    assert_eq!(shared.count(), 7);
+    assert!(shared.0.try_lock().is_ok());
+
+    let cell = Guarded::RefCell(RefCell::new(Hits(2)));
+    assert_eq!(cell.count() + cell.count(), 4);
+}
+
+#[test]
+fn delegates_through_struct_field() {
+    let mut stats = Stats { hits: RwLock::new(Hits(0)), name: "stats" };
+    stats.add(3);
+    assert_eq!(stats.count(), 3);
+    assert_eq!(stats.name, "stats");
+    assert_eq!(stats.into_count(), 3);
+}
@@ -0,0 +1,56 @@
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn get(&self) -> u32;
+
+    fn increment(&mut self);
+
+    fn into_inner(self) -> u32;
+}
+
+struct Count(u32);
+
+impl Counter for Count {
+    fn get(&self) -> u32 {
+        self.0
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+
+    fn into_inner(self) -> u32 {
+        self.0
+    }
+}
+
+#[delegate(derive(Counter))]
+struct Tracked {
+    id: u64,
+    #[delegate(to)]
+    count: Count,
+    hits: Vec<&'static str>,
+}
+
+#[delegate(derive(Counter))]
+struct Labeled(&'static str, #[delegate(to)] Count);
+
+#[test]
+fn delegates_to_marked_named_field() {
+    let mut tracked = Tracked { id: 7, count: Count(1), hits: vec!["a"] };
+    tracked.increment();
+    assert_eq!(tracked.get(), 2);
+    assert_eq!(tracked.id, 7);
+    assert_eq!(tracked.hits, ["a"]);
+    assert_eq!(tracked.into_inner(), 2);
+}
+
+#[test]
+fn delegates_to_marked_unnamed_field() {
+    let mut labeled = Labeled("label", Count(3));
+    labeled.increment();
+    assert_eq!(labeled.get(), 4);
+    assert_eq!(labeled.0, "label");
+    assert_eq!(labeled.into_inner(), 4);
+}
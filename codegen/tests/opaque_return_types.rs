@@ -0,0 +1,151 @@
+use std::{
+    future::Future,
+    iter,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use delegation::delegate;
+
+#[delegate]
+trait Source {
+    fn values(&self) -> impl Iterator<Item = u8> + '_;
+
+    fn into_values(self) -> impl DoubleEndedIterator<Item = u8>;
+
+    async fn load(&self, scale: u8) -> u8;
+
+    async fn count_in(&self, input: &str) -> usize;
+
+    fn load_sendable(&self) -> impl Future<Output = u8> + Send;
+}
+
+struct List(Vec<u8>);
+
+impl Source for List {
+    fn values(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied()
+    }
+
+    fn into_values(self) -> impl DoubleEndedIterator<Item = u8> {
+        self.0.into_iter()
+    }
+
+    async fn load(&self, scale: u8) -> u8 {
+        self.0.iter().sum::<u8>() * scale
+    }
+
+    async fn count_in(&self, input: &str) -> usize {
+        input.bytes().filter(|b| self.0.contains(b)).count()
+    }
+
+    fn load_sendable(&self) -> impl Future<Output = u8> + Send {
+        let len = u8::try_from(self.0.len()).unwrap();
+        async move { len }
+    }
+}
+
+struct Repeat(u8, usize);
+
+impl Source for Repeat {
+    fn values(&self) -> impl Iterator<Item = u8> + '_ {
+        iter::repeat_n(self.0, self.1)
+    }
+
+    fn into_values(self) -> impl DoubleEndedIterator<Item = u8> {
+        iter::repeat_n(self.0, self.1)
+    }
+
+    async fn load(&self, scale: u8) -> u8 {
+        Yield::default().await;
+        self.0 * scale
+    }
+
+    async fn count_in(&self, input: &str) -> usize {
+        Yield::default().await;
+        input.bytes().filter(|b| *b == self.0).count() * self.1
+    }
+
+    fn load_sendable(&self) -> impl Future<Output = u8> + Send {
+        let value = self.0;
+        async move {
+            Yield::default().await;
+            value
+        }
+    }
+}
+
+#[delegate(derive(Source))]
+enum AnySource {
+    List(List),
+    Repeat(Repeat),
+    Empty { source: List },
+}
+
+#[delegate(derive(Source))]
+struct Wrapped(AnySource);
+
+#[derive(Default)]
+struct Yield(bool);
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+fn assert_send<T: Send>(val: T) -> T {
+    val
+}
+
+#[test]
+fn sums_iterators() {
+    let list = AnySource::List(List(vec![1, 2, 3]));
+    let repeat = AnySource::Repeat(Repeat(7, 2));
+    let empty = AnySource::Empty { source: List(vec![]) };
+
+    assert_eq!(list.values().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(repeat.values().collect::<Vec<_>>(), [7, 7]);
+    assert_eq!(empty.values().count(), 0);
+
+    assert_eq!(list.into_values().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    #[expect(clippy::unnecessary_fold, reason = "delegated `fold` is tested")]
+    let sum = repeat.into_values().fold(0, |acc, v| acc + v);
+    assert_eq!(sum, 14);
+    assert_eq!(empty.into_values().next_back(), None);
+}
+
+#[test]
+fn sums_futures() {
+    let list = AnySource::List(List(vec![1, 2]));
+    let repeat = Wrapped(AnySource::Repeat(Repeat(5, 1)));
+
+    assert_eq!(block_on(list.load(2)), 6);
+    assert_eq!(block_on(repeat.load(3)), 15);
+
+    assert_eq!(block_on(list.count_in("abba")), 0);
+    assert_eq!(block_on(repeat.count_in(&String::from("\x05\x05"))), 2);
+
+    assert_eq!(block_on(assert_send(list.load_sendable())), 2);
+    assert_eq!(block_on(assert_send(repeat.load_sendable())), 5);
+}
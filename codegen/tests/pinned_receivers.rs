@@ -0,0 +1,158 @@
+use std::{
+    future::{self, Future},
+    marker::PhantomPinned,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+
+use delegation::delegate;
+
+#[delegate]
+trait Counter {
+    fn bump(self: Pin<&mut Self>, by: u8) -> u8;
+
+    fn current(&self) -> u8;
+}
+
+#[derive(Default)]
+struct Pinned {
+    count: u8,
+    _pinned: PhantomPinned,
+}
+
+impl Counter for Pinned {
+    fn bump(self: Pin<&mut Self>, by: u8) -> u8 {
+        // SAFETY: `count` is not structurally pinned.
+        #[expect(unsafe_code, reason = "test")]
+        let this = unsafe { self.get_unchecked_mut() };
+        this.count += by;
+        this.count
+    }
+
+    fn current(&self) -> u8 {
+        self.count
+    }
+}
+
+struct Unpinned(u8);
+
+impl Counter for Unpinned {
+    fn bump(mut self: Pin<&mut Self>, by: u8) -> u8 {
+        self.0 += by * 2;
+        self.0
+    }
+
+    fn current(&self) -> u8 {
+        self.0
+    }
+}
+
+#[delegate(derive(Counter), pin)]
+enum AnyCounter {
+    Pinned(Pinned),
+    Unpinned { counter: Unpinned },
+}
+
+#[delegate(pin, derive(Counter))]
+struct Wrapped(AnyCounter);
+
+#[delegate(derive(Counter), pin)]
+enum UnpinCounter {
+    First(Unpinned),
+    Second(Unpinned),
+}
+
+mod without_pin_import {
+    use delegation::delegate;
+
+    use super::{Counter, Unpinned};
+
+    #[delegate(derive(Counter), pin)]
+    pub(super) enum NoImport {
+        Unpinned(Unpinned),
+    }
+}
+
+#[delegate(as = Future)]
+trait FutureDef {
+    type Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
+
+struct Countdown {
+    left: u8,
+    _pinned: PhantomPinned,
+}
+
+impl Future for Countdown {
+    type Output = u8;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+        // SAFETY: `left` is not structurally pinned.
+        #[expect(unsafe_code, reason = "test")]
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.left == 0 {
+            return Poll::Ready(42);
+        }
+        this.left -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[delegate(derive(Future as FutureDef), pin)]
+enum AnyFuture {
+    Ready(future::Ready<u8>),
+    Countdown(Countdown),
+}
+
+fn block_on<F: Future>(fut: F) -> (F::Output, usize) {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return (out, polls);
+        }
+    }
+}
+
+#[test]
+fn pinned_receiver_is_delegated() {
+    let mut counter = pin!(AnyCounter::Pinned(Pinned::default()));
+    assert_eq!(counter.as_mut().bump(1), 1);
+    assert_eq!(counter.as_mut().bump(2), 3);
+    assert_eq!(counter.current(), 3);
+
+    let mut counter =
+        pin!(Wrapped(AnyCounter::Unpinned { counter: Unpinned(1) }));
+    assert_eq!(counter.as_mut().bump(1), 3);
+    assert_eq!(counter.current(), 3);
+}
+
+#[test]
+fn unpin_delegates_keep_type_unpin() {
+    let mut counter = UnpinCounter::Second(Unpinned(0));
+    assert_eq!(Pin::new(&mut counter).bump(2), 4);
+    assert_eq!(counter.current(), 4);
+
+    let mut counter = UnpinCounter::First(Unpinned(1));
+    assert_eq!(Pin::new(&mut counter).bump(1), 3);
+
+    let mut counter = without_pin_import::NoImport::Unpinned(Unpinned(2));
+    assert_eq!(Pin::new(&mut counter).bump(1), 4);
+}
+
+#[test]
+fn future_is_delegated() {
+    assert_eq!(block_on(AnyFuture::Ready(future::ready(1))), (1, 1));
+    assert_eq!(
+        block_on(AnyFuture::Countdown(Countdown {
+            left: 2,
+            _pinned: PhantomPinned,
+        })),
+        (42, 3),
+    );
+}
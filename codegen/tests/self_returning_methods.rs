@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use delegation::delegate;
+
+#[delegate]
+trait Timeout {
+    fn timeout(&self) -> Duration;
+
+    fn with_timeout(self, timeout: Duration) -> Self;
+
+    fn normalized(&self) -> Self;
+
+    fn reset(&mut self) -> Self;
+
+    fn boxed(self: Box<Self>) -> Self;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Http {
+    timeout: Duration,
+}
+
+impl Timeout for Http {
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout }
+    }
+
+    fn normalized(&self) -> Self {
+        Self { timeout: self.timeout.min(Duration::from_secs(60)) }
+    }
+
+    fn reset(&mut self) -> Self {
+        let prev = self.clone();
+        self.timeout = Duration::ZERO;
+        prev
+    }
+
+    fn boxed(self: Box<Self>) -> Self {
+        *self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Grpc(u64);
+
+impl Timeout for Grpc {
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.0)
+    }
+
+    fn with_timeout(self, timeout: Duration) -> Self {
+        Self(timeout.as_millis().try_into().unwrap())
+    }
+
+    fn normalized(&self) -> Self {
+        Self(self.0.max(1))
+    }
+
+    fn reset(&mut self) -> Self {
+        Self(std::mem::take(&mut self.0))
+    }
+
+    fn boxed(self: Box<Self>) -> Self {
+        *self
+    }
+}
+
+#[delegate(derive(Timeout))]
+#[derive(Clone, Debug, PartialEq)]
+enum Config {
+    Http(Http),
+    Grpc { inner: Grpc },
+}
+
+#[delegate(derive(Timeout))]
+#[derive(Clone, Debug, PartialEq)]
+struct Named {
+    config: Config,
+}
+
+#[test]
+fn rewraps_into_same_variant() {
+    let http = Config::Http(Http { timeout: Duration::from_secs(100) });
+    assert_eq!(
+        http.normalized(),
+        Config::Http(Http { timeout: Duration::from_secs(60) }),
+    );
+    assert_eq!(
+        http.with_timeout(Duration::from_secs(1)),
+        Config::Http(Http { timeout: Duration::from_secs(1) }),
+    );
+
+    let mut grpc = Config::Grpc { inner: Grpc(5) };
+    assert_eq!(grpc.reset(), Config::Grpc { inner: Grpc(5) });
+    assert_eq!(grpc, Config::Grpc { inner: Grpc(0) });
+    assert_eq!(grpc.normalized(), Config::Grpc { inner: Grpc(1) });
+    assert_eq!(Box::new(grpc.clone()).boxed(), grpc);
+}
+
+#[test]
+fn rewraps_through_structs() {
+    let named = Named { config: Config::Grpc { inner: Grpc(0) } };
+    let named = named.with_timeout(Duration::from_millis(10));
+    assert_eq!(named.timeout(), Duration::from_millis(10));
+    assert_eq!(named.config, Config::Grpc { inner: Grpc(10) });
+}
+
+#[delegate(as = Clone)]
+trait CloneDef {
+    fn clone(&self) -> Self;
+}
+
+#[delegate(derive(Clone as CloneDef))]
+#[derive(Debug, PartialEq)]
+enum Cloneable {
+    Http(Http),
+    Grpc(Grpc),
+}
+
+#[test]
+fn rewraps_external_traits() {
+    let grpc = Cloneable::Grpc(Grpc(3));
+    assert_eq!(grpc.clone(), Cloneable::Grpc(Grpc(3)));
+}
@@ -0,0 +1,93 @@
+use delegation::delegate;
+
+#[delegate]
+trait Shape {
+    fn area(&self) -> f64;
+
+    #[delegate(skip)]
+    fn describe(&self) -> String {
+        format!("shape of {:.1} area", self.area())
+    }
+
+    fn name(&self) -> String {
+        "shape".into()
+    }
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("square of {:.1} side", self.0)
+    }
+
+    fn name(&self) -> String {
+        "square".into()
+    }
+}
+
+struct Rect(f64, f64);
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.0 * self.1
+    }
+
+    fn name(&self) -> String {
+        "rect".into()
+    }
+}
+
+#[delegate(derive(Shape))]
+enum AnyShape {
+    Square(Square),
+    Rect(Rect),
+}
+
+#[delegate(derive(Shape(skip(name))))]
+struct Unnamed(AnyShape);
+
+#[test]
+fn keeps_default_impl_of_skipped_methods() {
+    let square = AnyShape::Square(Square(2.0));
+    assert_eq!(square.describe(), "shape of 4.0 area");
+    assert_eq!(square.name(), "square");
+
+    let rect = AnyShape::Rect(Rect(2.0, 3.0));
+    assert_eq!(rect.describe(), "shape of 6.0 area");
+    assert_eq!(rect.name(), "rect");
+}
+
+#[test]
+fn keeps_default_impl_of_methods_skipped_on_derive() {
+    let unnamed = Unnamed(AnyShape::Square(Square(3.0)));
+    assert!((unnamed.area() - 9.0).abs() < f64::EPSILON);
+    assert_eq!(unnamed.describe(), "shape of 9.0 area");
+    assert_eq!(unnamed.name(), "shape");
+}
+
+#[delegate(as = Clone)]
+trait CloneDef {
+    fn clone(&self) -> Self;
+
+    #[delegate(skip)]
+    fn clone_from(&mut self, source: &Self);
+}
+
+#[delegate(derive(Clone as CloneDef))]
+#[derive(Debug, PartialEq)]
+enum Number {
+    Int(i32),
+    Float(f32),
+}
+
+#[test]
+fn skips_methods_of_external_traits() {
+    let mut num = Number::Int(1);
+    num.clone_from(&Number::Float(2.0));
+    assert_eq!(num, Number::Float(2.0));
+}
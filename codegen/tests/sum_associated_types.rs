@@ -0,0 +1,137 @@
+use std::{iter, ops::Range};
+
+use delegation::delegate;
+
+#[delegate]
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl Describe for i32 {
+    fn describe(&self) -> String {
+        format!("number {self}")
+    }
+}
+
+impl Describe for String {
+    fn describe(&self) -> String {
+        format!("text {self:?}")
+    }
+}
+
+#[delegate]
+trait Handler {
+    #[delegate(sum)]
+    type Output: Describe;
+
+    fn handle(&self, input: &str) -> Self::Output;
+
+    fn last(&mut self) -> <Self as Handler>::Output;
+
+    fn into_output(self) -> Self::Output;
+}
+
+struct Count(i32);
+
+impl Handler for Count {
+    type Output = i32;
+
+    fn handle(&self, input: &str) -> i32 {
+        self.0 + i32::try_from(input.len()).unwrap_or_default()
+    }
+
+    fn last(&mut self) -> i32 {
+        self.0
+    }
+
+    fn into_output(self) -> i32 {
+        self.0
+    }
+}
+
+struct Echo(String);
+
+impl Handler for Echo {
+    type Output = String;
+
+    fn handle(&self, input: &str) -> String {
+        format!("{}{input}", self.0)
+    }
+
+    fn last(&mut self) -> String {
+        self.0.clone()
+    }
+
+    fn into_output(self) -> String {
+        self.0
+    }
+}
+
+#[delegate(derive(Handler))]
+enum AnyHandler {
+    Count(Count),
+    Echo { handler: Echo },
+}
+
+#[delegate(derive(Handler))]
+struct Wrapped(AnyHandler);
+
+#[delegate(as = Iterator)]
+trait IteratorDef {
+    type Item;
+
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[delegate(as = IntoIterator)]
+trait IntoIteratorDef {
+    type Item;
+
+    #[delegate(sum as IteratorDef)]
+    type IntoIter: Iterator<Item = Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter;
+}
+
+#[delegate(derive(IntoIterator as IntoIteratorDef))]
+enum Numbers {
+    List(Vec<u8>),
+    Range(Range<u8>),
+    Repeat(iter::RepeatN<u8>),
+}
+
+#[test]
+fn wraps_variant_dependent_types() {
+    let count = AnyHandler::Count(Count(1));
+    let echo = AnyHandler::Echo { handler: Echo("hi ".into()) };
+
+    assert_eq!(count.handle("abc").describe(), "number 4");
+    assert_eq!(echo.handle("there").describe(), "text \"hi there\"");
+    assert_eq!(count.into_output().describe(), "number 1");
+    assert_eq!(echo.into_output().describe(), "text \"hi \"");
+}
+
+#[test]
+fn wraps_for_newtypes() {
+    let mut wrapped = Wrapped(AnyHandler::Echo { handler: Echo("a".into()) });
+
+    assert_eq!(wrapped.last().describe(), "text \"a\"");
+    assert_eq!(wrapped.handle("b").describe(), "text \"ab\"");
+}
+
+#[test]
+fn wraps_into_external_trait() {
+    let list = Numbers::List(vec![1, 2]);
+    let range = Numbers::Range(3..5);
+    let repeat = Numbers::Repeat(iter::repeat_n(7, 2));
+
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(range.into_iter().collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(repeat.into_iter().collect::<Vec<_>>(), [7, 7]);
+
+    let mut total = 0;
+    for n in Numbers::List(vec![5, 6]) {
+        total += n;
+    }
+    assert_eq!(total, 11);
+}
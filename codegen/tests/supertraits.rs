@@ -0,0 +1,238 @@
+use delegation::delegate;
+
+#[delegate]
+trait Named {
+    fn name(&self) -> String;
+}
+
+#[delegate]
+trait Area {
+    fn area(&self) -> f64;
+}
+
+#[delegate]
+trait Scaled<T> {
+    fn scale(&mut self, by: T);
+}
+
+#[delegate]
+trait Shape: Named + Area + Scaled<f64> + Send {
+    fn corners(&self) -> usize;
+
+    fn describe(&self) -> String {
+        format!("{} of {} corners", self.name(), self.corners())
+    }
+}
+
+#[delegate]
+trait Solid
+where
+    Self: Shape,
+{
+    fn volume(&self, height: f64) -> f64;
+}
+
+struct Square(f64);
+
+impl Named for Square {
+    fn name(&self) -> String {
+        "square".into()
+    }
+}
+
+impl Area for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+impl Scaled<f64> for Square {
+    fn scale(&mut self, by: f64) {
+        self.0 *= by;
+    }
+}
+
+impl Shape for Square {
+    fn corners(&self) -> usize {
+        4
+    }
+}
+
+impl Solid for Square {
+    fn volume(&self, height: f64) -> f64 {
+        self.area() * height
+    }
+}
+
+struct Triangle {
+    base: f64,
+    height: f64,
+}
+
+impl Named for Triangle {
+    fn name(&self) -> String {
+        "triangle".into()
+    }
+}
+
+impl Area for Triangle {
+    fn area(&self) -> f64 {
+        self.base * self.height / 2.0
+    }
+}
+
+impl Scaled<f64> for Triangle {
+    fn scale(&mut self, by: f64) {
+        self.base *= by;
+        self.height *= by;
+    }
+}
+
+impl Shape for Triangle {
+    fn corners(&self) -> usize {
+        3
+    }
+
+    fn describe(&self) -> String {
+        "pointy".into()
+    }
+}
+
+impl Solid for Triangle {
+    fn volume(&self, height: f64) -> f64 {
+        self.area() * height / 3.0
+    }
+}
+
+#[delegate(derive(Named, Area, Scaled<f64>, Shape, Solid))]
+enum AnyShape {
+    Square(Square),
+    Triangle(Triangle),
+}
+
+#[delegate(derive(Named, Area, Scaled<f64>, Shape))]
+struct Labeled(AnyShape);
+
+mod source {
+    pub trait Source {
+        type Item;
+
+        fn get(&self) -> Self::Item;
+    }
+
+    pub trait Peek: Source {
+        fn peek(&self) -> Option<Self::Item>;
+    }
+}
+
+#[delegate(as = source::Source)]
+trait SourceDef {
+    type Item;
+
+    fn get(&self) -> Self::Item;
+}
+
+#[delegate(as = source::Peek)]
+trait PeekDef: source::Source {
+    #[delegate(supertrait = source::Source)]
+    type Item;
+
+    fn peek(&self) -> Option<Self::Item>;
+}
+
+// Wrapper of the external definition should implement its supertraits too.
+impl<T: source::Source> source::Source for __delegate_PeekDef__Wrapper<T> {
+    type Item = T::Item;
+
+    fn get(&self) -> Self::Item {
+        self.0.get()
+    }
+}
+
+struct Fixed(u8);
+
+impl source::Source for Fixed {
+    type Item = u8;
+
+    fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl source::Peek for Fixed {
+    fn peek(&self) -> Option<u8> {
+        Some(self.0)
+    }
+}
+
+struct Empty;
+
+impl source::Source for Empty {
+    type Item = u8;
+
+    fn get(&self) -> u8 {
+        0
+    }
+}
+
+impl source::Peek for Empty {
+    fn peek(&self) -> Option<u8> {
+        None
+    }
+}
+
+#[delegate(derive(source::Source as SourceDef, source::Peek as PeekDef))]
+enum AnySource {
+    Fixed(Fixed),
+    Empty(Empty),
+}
+
+#[test]
+fn delegates_supertraits() {
+    let mut shape = AnyShape::Square(Square(2.0));
+    assert_eq!(shape.name(), "square");
+    assert_eq!(shape.corners(), 4);
+    shape.scale(2.0);
+    assert!((shape.area() - 16.0).abs() < f64::EPSILON);
+
+    let shape = AnyShape::Triangle(Triangle { base: 2.0, height: 3.0 });
+    assert_eq!(shape.name(), "triangle");
+    assert!((shape.area() - 3.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn delegates_through_layers() {
+    let square = AnyShape::Square(Square(3.0));
+    assert!((square.volume(2.0) - 18.0).abs() < f64::EPSILON);
+
+    let triangle = AnyShape::Triangle(Triangle { base: 2.0, height: 3.0 });
+    assert!((triangle.volume(2.0) - 2.0).abs() < f64::EPSILON);
+
+    let mut labeled = Labeled(triangle);
+    labeled.scale(2.0);
+    assert_eq!(labeled.name(), "triangle");
+    assert!((labeled.area() - 12.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn delegates_default_methods() {
+    let square = AnyShape::Square(Square(1.0));
+    assert_eq!(square.describe(), "square of 4 corners");
+
+    let triangle = AnyShape::Triangle(Triangle { base: 1.0, height: 1.0 });
+    assert_eq!(triangle.describe(), "pointy");
+    assert_eq!(Labeled(triangle).describe(), "pointy");
+}
+
+#[test]
+fn delegates_supertraits_associated_types() {
+    use source::{Peek as _, Source as _};
+
+    let fixed = AnySource::Fixed(Fixed(3));
+    assert_eq!(fixed.get(), 3);
+    assert_eq!(fixed.peek(), Some(3));
+
+    let empty = AnySource::Empty(Empty);
+    assert_eq!(empty.get(), 0);
+    assert_eq!(empty.peek(), None);
+}
@@ -0,0 +1,130 @@
+use std::{any::Any, rc::Rc, sync::Arc};
+
+use delegation::delegate;
+
+#[delegate]
+trait Task {
+    fn name(&self) -> String;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    fn share(self: Rc<Self>, suffix: char) -> String;
+
+    fn spawn(self: Arc<Self>) -> (String, usize);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Print(String);
+
+impl Task for Print {
+    fn name(&self) -> String {
+        format!("print {}", self.0)
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn share(self: Rc<Self>, suffix: char) -> String {
+        format!("{}{suffix}", self.name())
+    }
+
+    fn spawn(self: Arc<Self>) -> (String, usize) {
+        (self.name(), Arc::strong_count(&self))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Sleep {
+    secs: u64,
+}
+
+impl Task for Sleep {
+    fn name(&self) -> String {
+        format!("sleep {}", self.secs)
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn share(self: Rc<Self>, suffix: char) -> String {
+        format!("{}{suffix}", self.name())
+    }
+
+    fn spawn(self: Arc<Self>) -> (String, usize) {
+        (self.name(), Arc::strong_count(&self))
+    }
+}
+
+#[delegate(derive(Task))]
+#[derive(Clone, Debug)]
+enum AnyTask {
+    Print(Print),
+    Sleep { task: Sleep },
+}
+
+#[delegate(derive(Task))]
+#[derive(Debug)]
+struct Named(AnyTask);
+
+#[delegate]
+trait Erase {
+    fn erase(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl Erase for u8 {
+    fn erase(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl Erase for String {
+    fn erase(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[delegate(derive(Erase))]
+enum Value {
+    Byte(u8),
+    Text(String),
+}
+
+#[test]
+fn boxed_receiver_is_delegated() {
+    let task = Box::new(AnyTask::Sleep { task: Sleep { secs: 3 } });
+    assert_eq!(task.name(), "sleep 3");
+    let any = task.into_any();
+    assert_eq!(any.downcast_ref::<Sleep>(), Some(&Sleep { secs: 3 }));
+
+    let named = Box::new(Named(AnyTask::Print(Print("hi".into()))));
+    let any = named.into_any();
+    assert_eq!(any.downcast_ref::<Print>(), Some(&Print("hi".into())));
+
+    let text = Box::new(Value::Text("text".into())).erase();
+    assert_eq!(text.downcast_ref::<String>().map(String::as_str), Some("text"));
+    let byte = Box::new(Value::Byte(1)).erase();
+    assert_eq!(byte.downcast_ref::<u8>(), Some(&1));
+}
+
+#[test]
+fn rc_receiver_is_delegated() {
+    let task = Rc::new(AnyTask::Print(Print("hi".into())));
+    let shared = Rc::clone(&task);
+
+    assert_eq!(task.share('!'), "print hi!");
+    assert_eq!(Rc::strong_count(&shared), 1);
+    assert_eq!(shared.share('?'), "print hi?");
+}
+
+#[test]
+fn arc_receiver_is_delegated() {
+    let task = Arc::new(AnyTask::Sleep { task: Sleep { secs: 1 } });
+    assert_eq!(task.spawn(), ("sleep 1".into(), 1));
+
+    let task = Arc::new(Named(AnyTask::Print(Print("hi".into()))));
+    let shared = Arc::clone(&task);
+    assert_eq!(task.spawn(), ("print hi".into(), 1));
+    assert_eq!(shared.name(), "print hi");
+}
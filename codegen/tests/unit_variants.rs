@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use delegation::delegate;
+
+#[delegate]
+trait Backend {
+    fn get(&self, key: &str) -> Option<String>;
+
+    fn set(&mut self, key: &str, value: String);
+
+    fn into_name(self) -> String;
+
+    fn new() -> Self;
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Memory(HashMap<String, String>);
+
+impl Backend for Memory {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        drop(self.0.insert(key.into(), value));
+    }
+
+    fn into_name(self) -> String {
+        "memory".into()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+struct Noop;
+
+impl Backend for Noop {
+    fn get(&self, _: &str) -> Option<String> {
+        None
+    }
+
+    fn set(&mut self, _: &str, _: String) {}
+
+    fn into_name(self) -> String {
+        "noop".into()
+    }
+
+    fn new() -> Self {
+        Self
+    }
+}
+
+#[delegate(derive(Backend))]
+#[derive(Debug, PartialEq)]
+enum AnyBackend {
+    Memory(Memory),
+    #[delegate(as_value = Noop, default)]
+    Disabled,
+}
+
+#[delegate(derive(Backend), as_value = Noop)]
+#[derive(Debug, PartialEq)]
+struct Disabled;
+
+#[delegate(derive(Backend))]
+struct Named(AnyBackend);
+
+#[test]
+fn delegates_other_variants() {
+    let mut memory = AnyBackend::Memory(Memory::default());
+    memory.set("key", "value".into());
+    assert_eq!(memory.get("key").as_deref(), Some("value"));
+    assert_eq!(memory.into_name(), "memory");
+}
+
+#[test]
+fn delegates_unit_variants_to_value() {
+    let mut disabled = AnyBackend::Disabled;
+    disabled.set("key", "value".into());
+    assert_eq!(disabled.get("key"), None);
+    assert_eq!(disabled.into_name(), "noop");
+    assert_eq!(AnyBackend::new(), AnyBackend::Disabled);
+}
+
+#[test]
+fn delegates_structs_without_fields_to_value() {
+    let mut disabled = Disabled;
+    disabled.set("key", "value".into());
+    assert_eq!(disabled.get("key"), None);
+    assert_eq!(disabled.into_name(), "noop");
+    assert_eq!(Disabled::new(), Disabled);
+}
+
+#[test]
+fn delegates_unit_variants_through_structs() {
+    let named = Named(AnyBackend::Disabled);
+    assert_eq!(named.get("key"), None);
+    assert_eq!(named.into_name(), "noop");
+}
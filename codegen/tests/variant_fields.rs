@@ -0,0 +1,83 @@
+use delegation::delegate;
+
+#[delegate]
+trait Connection {
+    fn send(&mut self, data: &str) -> usize;
+
+    fn is_secure(&self) -> bool;
+
+    fn close(self) -> String;
+}
+
+#[derive(Default)]
+struct TcpConn {
+    sent: usize,
+}
+
+impl Connection for TcpConn {
+    fn send(&mut self, data: &str) -> usize {
+        self.sent += data.len();
+        self.sent
+    }
+
+    fn is_secure(&self) -> bool {
+        false
+    }
+
+    fn close(self) -> String {
+        format!("tcp: {}", self.sent)
+    }
+}
+
+#[derive(Default)]
+struct TlsConn(TcpConn);
+
+impl Connection for TlsConn {
+    fn send(&mut self, data: &str) -> usize {
+        self.0.send(data)
+    }
+
+    fn is_secure(&self) -> bool {
+        true
+    }
+
+    fn close(self) -> String {
+        format!("tls: {}", self.0.sent)
+    }
+}
+
+#[delegate(derive(Connection))]
+enum AnyConn {
+    Tcp {
+        #[delegate(to)]
+        conn: TcpConn,
+        peer: &'static str,
+    },
+    Tls(u16, #[delegate(to)] TlsConn),
+    Local(TcpConn),
+}
+
+#[test]
+fn delegates_to_marked_named_field() {
+    let mut conn = AnyConn::Tcp { conn: TcpConn::default(), peer: "peer" };
+    assert_eq!(conn.send("abc"), 3);
+    assert!(!conn.is_secure());
+    assert!(matches!(conn, AnyConn::Tcp { peer: "peer", .. }));
+    assert_eq!(conn.close(), "tcp: 3");
+}
+
+#[test]
+fn delegates_to_marked_unnamed_field() {
+    let mut conn = AnyConn::Tls(443, TlsConn::default());
+    assert_eq!(conn.send("ab"), 2);
+    assert!(conn.is_secure());
+    assert!(matches!(conn, AnyConn::Tls(443, _)));
+    assert_eq!(conn.close(), "tls: 2");
+}
+
+#[test]
+fn delegates_to_single_field() {
+    let mut conn = AnyConn::Local(TcpConn::default());
+    assert_eq!(conn.send("a"), 1);
+    assert_eq!(conn.close(), "tcp: 1");
+}
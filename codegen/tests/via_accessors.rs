@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use delegation::delegate;
+
+#[delegate]
+trait Backend {
+    fn name(&self) -> String;
+
+    fn store(&mut self, value: u32);
+
+    fn into_stored(self) -> Vec<u32>;
+}
+
+#[delegate]
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+#[derive(Clone, Default)]
+struct Memory(Vec<u32>);
+
+impl Backend for Memory {
+    fn name(&self) -> String {
+        format!("memory {:?}", self.0)
+    }
+
+    fn store(&mut self, value: u32) {
+        self.0.push(value);
+    }
+
+    fn into_stored(self) -> Vec<u32> {
+        self.0
+    }
+}
+
+impl Describe for Memory {
+    fn describe(&self) -> String {
+        format!("{} values", self.0.len())
+    }
+}
+
+#[delegate(
+    derive(Backend, Describe),
+    via = Self::current -> Memory,
+    via_mut = Self::current_mut,
+    via_owned = Self::into_current,
+)]
+struct Replicas {
+    backends: [Memory; 2],
+    active: usize,
+}
+
+impl Replicas {
+    fn current(&self) -> &Memory {
+        &self.backends[self.active]
+    }
+
+    fn current_mut(&mut self) -> &mut Memory {
+        &mut self.backends[self.active]
+    }
+
+    fn into_current(self) -> Memory {
+        let [first, second] = self.backends;
+        if self.active == 0 { first } else { second }
+    }
+}
+
+#[delegate(derive(Describe), via = Self::primary -> Memory)]
+struct Registry {
+    backends: HashMap<&'static str, Memory>,
+}
+
+impl Registry {
+    fn primary(&self) -> &Memory {
+        &self.backends["primary"]
+    }
+}
+
+fn last(backends: &[Memory]) -> &Memory {
+    backends.last().expect("non-empty")
+}
+
+fn last_mut(backends: &mut [Memory]) -> &mut Memory {
+    backends.last_mut().expect("non-empty")
+}
+
+fn into_last(mut backends: Vec<Memory>) -> Memory {
+    backends.pop().expect("non-empty")
+}
+
+#[delegate(derive(Describe, Backend))]
+enum Storage {
+    Single(Memory),
+    #[delegate(
+        via = last -> Memory,
+        via_mut = last_mut,
+        via_owned = into_last,
+    )]
+    Chain(Vec<Memory>),
+}
+
+#[test]
+fn delegates_struct_via_accessors() {
+    let mut replicas =
+        Replicas { backends: [Memory(vec![1]), Memory(vec![2])], active: 1 };
+    replicas.store(3);
+    assert_eq!(replicas.name(), "memory [2, 3]");
+    assert_eq!(replicas.describe(), "2 values");
+
+    replicas.active = 0;
+    assert_eq!(replicas.name(), "memory [1]");
+    assert_eq!(replicas.into_stored(), vec![1]);
+}
+
+#[test]
+fn delegates_struct_via_shared_accessor_only() {
+    let registry = Registry {
+        backends: HashMap::from([("primary", Memory(vec![1, 2, 3]))]),
+    };
+    assert_eq!(registry.describe(), "3 values");
+}
+
+#[test]
+fn delegates_variant_via_accessors() {
+    let mut storage = Storage::Chain(vec![Memory(vec![1]), Memory::default()]);
+    storage.store(2);
+    assert_eq!(storage.name(), "memory [2]");
+    assert_eq!(storage.describe(), "1 values");
+    assert_eq!(storage.into_stored(), vec![2]);
+
+    let storage = Storage::Single(Memory(vec![5]));
+    assert_eq!(storage.into_stored(), vec![5]);
+}
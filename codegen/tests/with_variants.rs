@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use delegation::delegate;
+
+#[delegate]
+trait Backend {
+    fn get(&self, key: &str) -> Option<String>;
+
+    fn set(&mut self, key: &str, value: String);
+
+    fn into_name(self) -> String;
+
+    fn same_as(&self, other: &Self) -> bool {
+        _ = other;
+        false
+    }
+}
+
+#[derive(Default)]
+struct Memory(HashMap<String, String>);
+
+impl Backend for Memory {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        drop(self.0.insert(key.into(), value));
+    }
+
+    fn into_name(self) -> String {
+        "memory".into()
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+struct Disabled {
+    reason: &'static str,
+}
+
+mod disabled {
+    use super::{AnyBackend, Disabled};
+
+    pub(super) fn get(this: &Disabled, _: &str) -> Option<String> {
+        Some(this.reason.into())
+    }
+
+    pub(super) fn set(_: &mut Disabled, _: &str, _: String) {}
+
+    pub(super) fn into_name(this: Disabled) -> String {
+        format!("disabled: {}", this.reason)
+    }
+
+    pub(super) fn same_as(this: &Disabled, other: &AnyBackend) -> bool {
+        matches!(other, AnyBackend::Disabled { inner } if inner.reason == this.reason)
+    }
+}
+
+#[delegate(derive(Backend))]
+enum AnyBackend {
+    Memory(Memory),
+    #[delegate(with = disabled)]
+    Disabled {
+        inner: Disabled,
+    },
+}
+
+#[delegate(derive(Backend))]
+struct Named(AnyBackend);
+
+#[test]
+fn delegates_other_variants() {
+    let mut memory = AnyBackend::Memory(Memory::default());
+    memory.set("key", "value".into());
+    assert_eq!(memory.get("key").as_deref(), Some("value"));
+    assert!(memory.same_as(&AnyBackend::Memory(Memory(HashMap::from([(
+        "key".into(),
+        "value".into(),
+    )])))));
+    assert_eq!(memory.into_name(), "memory");
+}
+
+#[test]
+fn calls_with_functions() {
+    let mut disabled =
+        AnyBackend::Disabled { inner: Disabled { reason: "off" } };
+    disabled.set("key", "value".into());
+    assert_eq!(disabled.get("key").as_deref(), Some("off"));
+    assert!(
+        disabled.same_as(&AnyBackend::Disabled {
+            inner: Disabled { reason: "off" },
+        })
+    );
+    assert!(!disabled.same_as(&AnyBackend::Memory(Memory::default())));
+    assert_eq!(disabled.into_name(), "disabled: off");
+}
+
+#[test]
+fn falls_back_on_mismatched_with_arguments() {
+    let memory = AnyBackend::Memory(Memory::default());
+    assert!(
+        !memory.same_as(&AnyBackend::Disabled {
+            inner: Disabled { reason: "off" },
+        })
+    );
+}
+
+#[test]
+fn calls_with_functions_through_structs() {
+    let named =
+        Named(AnyBackend::Disabled { inner: Disabled { reason: "off" } });
+    assert_eq!(named.get("key").as_deref(), Some("off"));
+    assert_eq!(named.into_name(), "disabled: off");
+}
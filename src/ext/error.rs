@@ -0,0 +1,47 @@
+//! Definition of the [`core::error::Error`] trait.
+//!
+//! ```rust
+//! use std::{error::Error as _, fmt, io, num};
+//!
+//! use delegation::{delegate, ext};
+//!
+//! #[delegate(derive(
+//!     std::error::Error as ext::error::Error,
+//!     fmt::Display as ext::fmt::Display,
+//!     fmt::Debug as ext::fmt::Debug,
+//! ))]
+//! enum AppError {
+//!     Io(io::Error),
+//!     Parse(num::ParseIntError),
+//! }
+//!
+//! let err = AppError::Parse("x".parse::<u8>().unwrap_err());
+//! assert_eq!(err.to_string(), "invalid digit found in string");
+//! assert!(err.source().is_none());
+//! ```
+
+use core::{error, fmt};
+
+use crate::delegate;
+
+/// Definition of the [`error::Error`] trait.
+#[delegate(as = error::Error)]
+pub trait Error: fmt::Debug + fmt::Display {
+    /// Returns the lower-level source of this error, if any.
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>;
+}
+
+// `error::Error` requires its wrapper to implement the `fmt::Debug` and
+// `fmt::Display` supertraits, so they're forwarded to the delegating type
+// (deriving them as well).
+impl<T: fmt::Debug + ?Sized> fmt::Debug for __delegate_Error__Wrapper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for __delegate_Error__Wrapper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,99 @@
+//! Definitions of the [`core::fmt`] traits.
+//!
+//! ```rust
+//! use core::fmt;
+//!
+//! use delegation::{delegate, ext};
+//!
+//! #[delegate(derive(
+//!     fmt::Display as ext::fmt::Display,
+//!     fmt::LowerHex as ext::fmt::LowerHex,
+//! ))]
+//! enum Id {
+//!     Short(u8),
+//!     Long(u64),
+//! }
+//!
+//! assert_eq!(Id::Short(10).to_string(), "10");
+//! assert_eq!(format!("{:x}", Id::Long(255)), "ff");
+//! ```
+
+use core::fmt::{self, Arguments, Formatter};
+
+use crate::delegate;
+
+/// Definition of the [`fmt::Display`] trait.
+#[delegate(as = fmt::Display)]
+pub trait Display {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::Debug`] trait.
+#[delegate(as = fmt::Debug)]
+pub trait Debug {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::Binary`] trait.
+#[delegate(as = fmt::Binary)]
+pub trait Binary {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::Octal`] trait.
+#[delegate(as = fmt::Octal)]
+pub trait Octal {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::LowerHex`] trait.
+#[delegate(as = fmt::LowerHex)]
+pub trait LowerHex {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::UpperHex`] trait.
+#[delegate(as = fmt::UpperHex)]
+pub trait UpperHex {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::LowerExp`] trait.
+#[delegate(as = fmt::LowerExp)]
+pub trait LowerExp {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::UpperExp`] trait.
+#[delegate(as = fmt::UpperExp)]
+pub trait UpperExp {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::Pointer`] trait.
+#[delegate(as = fmt::Pointer)]
+pub trait Pointer {
+    /// Formats the value using the given [`Formatter`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Definition of the [`fmt::Write`] trait.
+#[delegate(as = fmt::Write)]
+pub trait Write {
+    /// Writes a string slice into this writer.
+    fn write_str(&mut self, s: &str) -> fmt::Result;
+
+    /// Writes a [`char`] into this writer.
+    fn write_char(&mut self, c: char) -> fmt::Result;
+
+    /// Writes formatted [`Arguments`] into this writer.
+    fn write_fmt(&mut self, args: Arguments<'_>) -> fmt::Result;
+}
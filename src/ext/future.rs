@@ -0,0 +1,43 @@
+//! Definitions of the [`core::future`] traits.
+//!
+//! Delegating types should opt into the structural pin projection with the
+//! `#[delegate(pin)]` attribute argument.
+//!
+//! ```rust
+//! use std::{
+//!     future::{self, Future as _},
+//!     pin::pin,
+//!     task::{Context, Poll, Waker},
+//! };
+//!
+//! use delegation::{delegate, ext};
+//!
+//! #[delegate(derive(Future as ext::future::Future), pin)]
+//! enum Either {
+//!     Ready(future::Ready<u8>),
+//!     Pending(future::Pending<u8>),
+//! }
+//!
+//! let mut cx = Context::from_waker(Waker::noop());
+//!
+//! let fut = pin!(Either::Ready(future::ready(1)));
+//! assert_eq!(fut.poll(&mut cx), Poll::Ready(1));
+//! ```
+
+use core::{
+    future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::delegate;
+
+/// Definition of the [`future::Future`] trait.
+#[delegate(as = future::Future)]
+pub trait Future {
+    /// Type of the value produced on completion.
+    type Output;
+
+    /// Attempts to resolve the future to a final value.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+}
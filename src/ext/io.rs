@@ -0,0 +1,135 @@
+//! Definitions of the [`std::io`] traits.
+//!
+//! Provided methods are delegated too, so the optimized implementations of
+//! the inner types are used.
+//!
+//! ```rust
+//! use std::io::{self, Cursor, Read as _};
+//!
+//! use delegation::{delegate, ext};
+//!
+//! #[delegate(derive(io::Read as ext::io::Read))]
+//! enum Source {
+//!     Bytes(Cursor<Vec<u8>>),
+//!     Empty(io::Empty),
+//! }
+//!
+//! let mut out = String::new();
+//! Source::Bytes(Cursor::new(b"data".to_vec()))
+//!     .read_to_string(&mut out)
+//!     .unwrap();
+//! assert_eq!(out, "data");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Arguments;
+use std::io::{self, IoSlice, IoSliceMut, SeekFrom};
+
+use crate::delegate;
+
+/// Definition of the [`io::Read`] trait.
+#[delegate(as = io::Read)]
+pub trait Read {
+    /// Pulls some bytes from this source into the specified buffer.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Like [`Read::read()`], except that it reads into a slice of buffers.
+    fn read_vectored(
+        &mut self,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> io::Result<usize>;
+
+    /// Reads all bytes until EOF in this source, appending them to `buf`.
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize>;
+
+    /// Reads all bytes until EOF in this source, appending them to `buf`.
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize>;
+
+    /// Reads the exact number of bytes required to fill `buf`.
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()>;
+}
+
+/// Definition of the [`io::Write`] trait.
+#[delegate(as = io::Write)]
+pub trait Write {
+    /// Writes a buffer into this writer, returning how many bytes were
+    /// written.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>;
+
+    /// Like [`Write::write()`], except that it writes from a slice of
+    /// buffers.
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize>;
+
+    /// Flushes this output stream.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Attempts to write an entire buffer into this writer.
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Writes formatted [`Arguments`] into this writer.
+    fn write_fmt(&mut self, args: Arguments<'_>) -> io::Result<()>;
+}
+
+/// Definition of the [`io::BufRead`] trait.
+#[delegate(as = io::BufRead)]
+pub trait BufRead: io::Read {
+    /// Returns the contents of the internal buffer, filling it with more data
+    /// from the inner reader if it's empty.
+    fn fill_buf(&mut self) -> io::Result<&[u8]>;
+
+    /// Marks the given `amount` of bytes as consumed from the buffer.
+    fn consume(&mut self, amount: usize);
+
+    /// Reads all bytes into `buf` until the `byte` delimiter or EOF is
+    /// reached.
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize>;
+
+    /// Skips all bytes until the `byte` delimiter or EOF is reached.
+    fn skip_until(&mut self, byte: u8) -> io::Result<usize>;
+
+    /// Reads all bytes until a newline is reached, appending them to `buf`.
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize>;
+}
+
+/// Definition of the [`io::Seek`] trait.
+#[delegate(as = io::Seek)]
+pub trait Seek {
+    /// Seeks to an offset, in bytes, in a stream.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64>;
+
+    /// Rewinds to the beginning of a stream.
+    fn rewind(&mut self) -> io::Result<()>;
+
+    /// Returns the current seek position from the start of the stream.
+    fn stream_position(&mut self) -> io::Result<u64>;
+
+    /// Seeks relative to the current position.
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()>;
+}
+
+// `io::BufRead` requires its wrapper to implement the `io::Read` supertrait,
+// so it's forwarded to the delegating type (deriving `Read` as well).
+impl<T: io::Read + ?Sized> io::Read for __delegate_BufRead__Wrapper<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(
+        &mut self,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.0.read_to_end(buf)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.0.read_to_string(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.0.read_exact(buf)
+    }
+}
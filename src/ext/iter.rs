@@ -0,0 +1,99 @@
+//! Definitions of the [`core::iter`] traits.
+//!
+//! ```rust
+//! use std::{ops, vec};
+//!
+//! use delegation::{delegate, ext};
+//!
+//! #[delegate(derive(
+//!     Iterator as ext::iter::Iterator,
+//!     DoubleEndedIterator as ext::iter::DoubleEndedIterator,
+//!     ExactSizeIterator as ext::iter::ExactSizeIterator,
+//! ))]
+//! enum Numbers {
+//!     Range(ops::Range<u32>),
+//!     Vec(vec::IntoIter<u32>),
+//! }
+//!
+//! let numbers = Numbers::Range(1..4);
+//! assert_eq!(numbers.len(), 3);
+//! assert_eq!(numbers.rev().collect::<Vec<_>>(), [3, 2, 1]);
+//! assert_eq!(Numbers::Vec(vec![1, 2].into_iter()).sum::<u32>(), 3);
+//! ```
+
+use core::iter;
+
+use crate::delegate;
+
+/// Definition of the [`iter::Iterator`] trait.
+#[delegate(as = iter::Iterator)]
+pub trait Iterator {
+    /// Type of the elements being iterated over.
+    type Item;
+
+    /// Advances the iterator and returns the next value.
+    fn next(&mut self) -> Option<Self::Item>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    fn size_hint(&self) -> (usize, Option<usize>);
+
+    /// Returns the `n`th element of the iterator.
+    fn nth(&mut self, n: usize) -> Option<Self::Item>;
+
+    /// Folds every element into an accumulator by applying the `f` operation.
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B;
+}
+
+/// Definition of the [`iter::DoubleEndedIterator`] trait.
+#[delegate(as = iter::DoubleEndedIterator)]
+pub trait DoubleEndedIterator: iter::Iterator {
+    /// Type of the elements being iterated over.
+    #[delegate(supertrait = iter::Iterator)]
+    type Item;
+
+    /// Removes and returns an element from the end of the iterator.
+    fn next_back(&mut self) -> Option<Self::Item>;
+}
+
+/// Definition of the [`iter::ExactSizeIterator`] trait.
+#[delegate(as = iter::ExactSizeIterator)]
+pub trait ExactSizeIterator: iter::Iterator {
+    /// Returns the exact remaining length of the iterator.
+    fn len(&self) -> usize;
+}
+
+/// Implements the [`iter::Iterator`] supertrait for the provided wrappers,
+/// forwarding it to the delegating type (deriving `Iterator` as well).
+macro_rules! forward_iterator {
+    ($( $wrapper:ident ),*) => {$(
+        impl<T: iter::Iterator> iter::Iterator for $wrapper<T> {
+            type Item = T::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.0.nth(n)
+            }
+
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                self.0.fold(init, f)
+            }
+        }
+    )*};
+}
+
+forward_iterator!(
+    __delegate_DoubleEndedIterator__Wrapper,
+    __delegate_ExactSizeIterator__Wrapper
+);
@@ -0,0 +1,9 @@
+//! Ready-made definitions of the standard library traits, to be delegated via
+//! `#[delegate(derive(Trait as delegation::ext::module::Trait))]`.
+
+pub mod error;
+pub mod fmt;
+pub mod future;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
@@ -6,8 +6,12 @@
     not(any(doc, all(test, not(clippy)))),
     doc = env!("CARGO_PKG_NAME"),
 )]
+#![no_std]
 #![deny(nonstandard_style, rustdoc::all, trivial_casts, trivial_numeric_casts)]
-#![forbid(non_ascii_idents, unsafe_code)]
+#![forbid(non_ascii_idents)]
+// Not forbidden, as the `#[delegate]` expansions of the `ext::future`
+// definitions allow it for structural pin projection.
+#![deny(unsafe_code)]
 #![warn(
     clippy::absolute_paths,
     clippy::allow_attributes,
@@ -153,6 +157,13 @@
     variant_size_differences
 )]
 
+extern crate alloc;
+// Allows the `#[delegate]` expansions inside this crate to refer to it.
+extern crate self as delegation;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod ext;
 #[doc(hidden)]
 pub mod private; // Not part of the public API.
 
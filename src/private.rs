@@ -1,3 +1,13 @@
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::{
+    cell::{Ref, RefCell},
+    marker::PhantomData,
+    ops::Deref,
+    pin::Pin,
+};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
 #[doc(hidden)]
 pub use codegen::impl_for;
 
@@ -51,6 +61,548 @@ pub trait Convert {
     fn convert_ref_mut(&mut self) -> Self::RefMut<'_>;
 }
 
+impl<L, R: Convert> Convert for Either<L, R> {
+    type Owned = Either<L, R::Owned>;
+    type Ref<'a>
+        = Either<&'a L, R::Ref<'a>>
+    where
+        Self: 'a;
+    type RefMut<'a>
+        = Either<&'a mut L, R::RefMut<'a>>
+    where
+        Self: 'a;
+
+    fn convert_owned(self) -> Self::Owned {
+        match self {
+            Self::Left(l) => Either::Left(l),
+            Self::Right(r) => Either::Right(r.convert_owned()),
+        }
+    }
+
+    fn convert_ref(&self) -> Self::Ref<'_> {
+        match self {
+            Self::Left(l) => Either::Left(l),
+            Self::Right(r) => Either::Right(r.convert_ref()),
+        }
+    }
+
+    fn convert_ref_mut(&mut self) -> Self::RefMut<'_> {
+        match self {
+            Self::Left(l) => Either::Left(l),
+            Self::Right(r) => Either::Right(r.convert_ref_mut()),
+        }
+    }
+}
+
+impl Convert for Void {
+    type Owned = Self;
+    type Ref<'a> = Self;
+    type RefMut<'a> = Self;
+
+    fn convert_owned(self) -> Self::Owned {
+        match self {}
+    }
+
+    #[expect( // never dereferenced, as cannot be created
+        clippy::uninhabited_references,
+        reason = "`Void` is uninhabited"
+    )]
+    fn convert_ref(&self) -> Self::Ref<'_> {
+        match *self {}
+    }
+
+    #[expect( // never dereferenced, as cannot be created
+        clippy::uninhabited_references,
+        reason = "`Void` is uninhabited"
+    )]
+    fn convert_ref_mut(&mut self) -> Self::RefMut<'_> {
+        match *self {}
+    }
+}
+
+/// Trait for converting a pinned type into its pinned delegate.
+///
+/// Implemented only for types opted into pin projection with the
+/// `#[delegate(pin)]` attribute argument, as their delegates must be
+/// structurally pinned.
+pub trait ConvertPin: Convert {
+    /// Type of pinned mutable referenced any enum variant.
+    type PinMut<'a>
+    where
+        Self: 'a;
+
+    /// Converts pinned mutable reference to this enum into a pinned mutable
+    /// variant reference.
+    fn convert_pin_mut(self: Pin<&mut Self>) -> Self::PinMut<'_>;
+}
+
+/// Trait for cloning a referenced any enum variant into an owned one.
+///
+/// Used for delegating methods with `self: Rc<Self>` or `self: Arc<Self>`
+/// receiver, whose value cannot be moved out of the pointer, unless it's
+/// unique.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be cloned into an owned variant",
+    note = "methods with `self: Rc<Self>` or `self: Arc<Self>` receiver can be \
+            delegated only to variants implementing `Clone`, and not guarded \
+            by locks"
+)]
+pub trait CloneRef {
+    /// Type of the cloned owned any enum variant.
+    type Owned;
+
+    /// Clones the referenced variant into an owned one.
+    fn clone_ref(self) -> Self::Owned;
+}
+
+impl<L: Clone, R: CloneRef> CloneRef for Either<&L, R> {
+    type Owned = Either<L, R::Owned>;
+
+    fn clone_ref(self) -> Self::Owned {
+        match self {
+            Self::Left(l) => Either::Left(l.clone()),
+            Self::Right(r) => Either::Right(r.clone_ref()),
+        }
+    }
+}
+
+impl CloneRef for Void {
+    type Owned = Self;
+
+    fn clone_ref(self) -> Self::Owned {
+        match self {}
+    }
+}
+
+/// Trait for converting an owned delegate back into its type.
+///
+/// Not implemented for types having external delegates, as they cannot be
+/// converted back.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be converted back from its delegates",
+    note = "methods returning `Self` cannot be delegated by types having \
+            external delegates (marked with `as` attribute argument) or \
+            fields besides the delegated one"
+)]
+pub trait ConvertBack: Convert + Sized {
+    /// Converts an owned variant back into this enum.
+    fn convert_back(owned: Self::Owned) -> Self;
+}
+
+/// Trait for converting a type into its default delegate.
+///
+/// Implemented only for enums having a variant marked with
+/// `#[delegate(default)]` and for structs, as associated functions without
+/// receiver have nothing else to be dispatched on.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no default delegate",
+    note = "associated functions without receiver are delegated to the enum \
+            variant marked with `#[delegate(default)]`"
+)]
+pub trait ConvertDefault: Sized {
+    /// Type of the default delegate.
+    type Default;
+
+    /// Converts the default delegate into this type.
+    fn convert_default(default: Self::Default) -> Self;
+}
+
+/// Trait for converting a pointer (or a reference) into its pointee, for
+/// delegates marked with the `deref` attribute argument.
+///
+/// Implemented only for `Box`, `Rc`, `Arc`, `&T` and `&mut T`, as moving out
+/// and mutably borrowing pointees differ per pointer.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not supported by `deref` attribute argument",
+    label = "cannot delegate through this field",
+    note = "only `Box`, `Rc`, `Arc`, `&T` and `&mut T` delegates can be \
+            dereferenced, consider delegating through accessors with \
+            `#[delegate(via = path -> Type)]` instead"
+)]
+pub trait DerefConvert: Deref {
+    /// Type of the owned pointee, or [`NotOwned`] if it cannot be moved out.
+    type Owned;
+
+    /// Type of the mutably referenced pointee, or [`NotMutable`] if it cannot
+    /// be borrowed mutably.
+    type RefMut<'a>
+    where
+        Self: 'a;
+
+    /// Moves the pointee out of this pointer.
+    fn deref_owned(self) -> Self::Owned;
+
+    /// Borrows the pointee of this pointer mutably.
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_>;
+
+    /// Converts the owned pointee back into a pointer.
+    fn from_owned(owned: Self::Owned) -> Self;
+}
+
+/// Pointer (or value obtained via accessors) whose delegate cannot be moved
+/// out, so implements no delegated traits.
+#[derive(Clone, Copy, Debug)]
+pub struct NotOwned<P>(pub P);
+
+/// Pointer (or value obtained via accessors) whose delegate cannot be
+/// borrowed mutably, so implements no delegated traits.
+#[derive(Debug)]
+pub struct NotMutable<'a, P>(pub &'a mut P);
+
+impl<T> DerefConvert for Box<T> {
+    type Owned = T;
+    type RefMut<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn deref_owned(self) -> Self::Owned {
+        *self
+    }
+
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_> {
+        self
+    }
+
+    fn from_owned(owned: Self::Owned) -> Self {
+        Self::new(owned)
+    }
+}
+
+impl<T: ?Sized> DerefConvert for Rc<T> {
+    type Owned = NotOwned<Self>;
+    type RefMut<'a>
+        = NotMutable<'a, Self>
+    where
+        Self: 'a;
+
+    fn deref_owned(self) -> Self::Owned {
+        NotOwned(self)
+    }
+
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_> {
+        NotMutable(self)
+    }
+
+    fn from_owned(owned: Self::Owned) -> Self {
+        owned.0
+    }
+}
+
+impl<T: ?Sized> DerefConvert for Arc<T> {
+    type Owned = NotOwned<Self>;
+    type RefMut<'a>
+        = NotMutable<'a, Self>
+    where
+        Self: 'a;
+
+    fn deref_owned(self) -> Self::Owned {
+        NotOwned(self)
+    }
+
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_> {
+        NotMutable(self)
+    }
+
+    fn from_owned(owned: Self::Owned) -> Self {
+        owned.0
+    }
+}
+
+impl<T: ?Sized> DerefConvert for &T {
+    type Owned = NotOwned<Self>;
+    type RefMut<'a>
+        = NotMutable<'a, Self>
+    where
+        Self: 'a;
+
+    fn deref_owned(self) -> Self::Owned {
+        NotOwned(self)
+    }
+
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_> {
+        NotMutable(self)
+    }
+
+    fn from_owned(owned: Self::Owned) -> Self {
+        owned.0
+    }
+}
+
+impl<T: ?Sized> DerefConvert for &mut T {
+    type Owned = NotOwned<Self>;
+    type RefMut<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn deref_owned(self) -> Self::Owned {
+        NotOwned(self)
+    }
+
+    fn deref_ref_mut(&mut self) -> Self::RefMut<'_> {
+        self
+    }
+
+    fn from_owned(owned: Self::Owned) -> Self {
+        owned.0
+    }
+}
+
+/// Trait for pointers (or references) whose pointee can be moved out.
+///
+/// Asserted for delegates marked with the `deref` attribute argument, if the
+/// delegated trait has methods taking `self`.
+#[diagnostic::on_unimplemented(
+    message = "methods taking `self` cannot be delegated through `{Self}` \
+               field",
+    label = "pointee cannot be moved out of this field",
+    note = "only `Box` pointees can be moved out, consider skipping such \
+            methods with `#[delegate(derive(Trait(skip(method))))]`"
+)]
+pub trait DerefOwned: DerefConvert {}
+
+impl<T> DerefOwned for Box<T> {}
+
+/// Trait for pointers (or references) whose pointee can be borrowed mutably.
+///
+/// Asserted for delegates marked with the `deref` attribute argument, if the
+/// delegated trait has methods taking `&mut self`.
+#[diagnostic::on_unimplemented(
+    message = "methods taking `&mut self` cannot be delegated through \
+               `{Self}` field",
+    label = "pointee cannot be borrowed mutably through this field",
+    note = "only `Box` and `&mut` pointees can be borrowed mutably, consider \
+            skipping such methods with \
+            `#[delegate(derive(Trait(skip(method))))]`"
+)]
+pub trait DerefMutable: DerefConvert {}
+
+impl<T> DerefMutable for Box<T> {}
+
+impl<T: ?Sized> DerefMutable for &mut T {}
+
+/// Type asserting the provided pointer to be [`DerefOwned`], once it's
+/// well-formed.
+#[derive(Debug)]
+pub struct AssertDerefOwned<P: DerefOwned + ?Sized>(PhantomData<P>);
+
+/// Type asserting the provided pointer to be [`DerefMutable`], once it's
+/// well-formed.
+#[derive(Debug)]
+pub struct AssertDerefMutable<P: DerefMutable + ?Sized>(PhantomData<P>);
+
+/// Trait for accessing a value behind a lock, for delegates marked with the
+/// `lock` attribute argument.
+///
+/// Poisoned locks are accessed regardless of their poisoning.
+pub trait Lock {
+    /// Type of the locked value.
+    type Target;
+
+    /// Type of the guard holding the lock.
+    type Guard<'a>: Deref<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Acquires the lock for reading the locked value.
+    fn lock(&self) -> Self::Guard<'_>;
+
+    /// Borrows the locked value mutably, without acquiring the lock.
+    fn get_mut(&mut self) -> &mut Self::Target;
+
+    /// Moves the locked value out of this lock.
+    fn into_inner(self) -> Self::Target;
+
+    /// Puts the provided value behind a new lock.
+    fn from_inner(inner: Self::Target) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl<T> Lock for Mutex<T> {
+    type Target = T;
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn lock(&self) -> Self::Guard<'_> {
+        Self::lock(self).unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Target {
+        Self::get_mut(self).unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn into_inner(self) -> Self::Target {
+        Self::into_inner(self).unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn from_inner(inner: Self::Target) -> Self {
+        Self::new(inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Lock for RwLock<T> {
+    type Target = T;
+    type Guard<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn lock(&self) -> Self::Guard<'_> {
+        self.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Target {
+        Self::get_mut(self).unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn into_inner(self) -> Self::Target {
+        Self::into_inner(self).unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn from_inner(inner: Self::Target) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T> Lock for RefCell<T> {
+    type Target = T;
+    type Guard<'a>
+        = Ref<'a, T>
+    where
+        Self: 'a;
+
+    fn lock(&self) -> Self::Guard<'_> {
+        self.borrow()
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Target {
+        Self::get_mut(self)
+    }
+
+    fn into_inner(self) -> Self::Target {
+        Self::into_inner(self)
+    }
+
+    fn from_inner(inner: Self::Target) -> Self {
+        Self::new(inner)
+    }
+}
+
+/// Reference to a [`Lock`], whose value is borrowed from a new guard on each
+/// delegated method call.
+#[derive(Debug)]
+pub struct Locked<'a, L: ?Sized>(pub &'a L);
+
+impl<L: ?Sized> Clone for Locked<'_, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: ?Sized> Copy for Locked<'_, L> {}
+
+impl<'a, L: Lock> Locked<'a, L> {
+    /// Borrows the locked value for the whole lifetime of this [`Locked`]
+    /// reference, which is impossible, as its guard is released on return.
+    ///
+    /// Used only by the methods never called for [`Locked`] delegates, as they
+    /// are checked to be [`Unlocked`].
+    ///
+    /// # Panics
+    ///
+    /// Always.
+    #[must_use]
+    pub fn unreachable(self) -> &'a L::Target {
+        _ = self;
+        unreachable!("locked delegates are checked to not be borrowed")
+    }
+}
+
+/// Trait for [`Either`] chains of referenced delegates, borrowed directly
+/// rather than from [`Locked`] guards.
+///
+/// Required only for delegating methods, whose results may borrow their
+/// delegates.
+#[diagnostic::on_unimplemented(
+    message = "methods returning borrows cannot be delegated to `{Self}`",
+    note = "delegates marked with `lock` attribute argument are borrowed from \
+            temporary guards, so methods returning borrows of them (or \
+            `impl Trait`, or having `Self` arguments or named receiver \
+            lifetimes) cannot be delegated"
+)]
+pub trait Unlocked {}
+
+impl<L: ?Sized, R: Unlocked> Unlocked for Either<&L, R> {}
+
+impl Unlocked for Void {}
+
+/// Trait for checking whether [`Either`] chains hold the same variant.
+pub trait SameVariant {
+    /// Checks whether this and the `other` [`Either`] chains hold the same
+    /// variant.
+    fn same_variant(&self, other: &Self) -> bool;
+}
+
+impl<L, R: SameVariant> SameVariant for Either<L, R> {
+    fn same_variant(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Left(_), Self::Left(_)) => true,
+            (Self::Right(r), Self::Right(other)) => r.same_variant(other),
+            (Self::Left(_), Self::Right(_))
+            | (Self::Right(_), Self::Left(_)) => false,
+        }
+    }
+}
+
+impl SameVariant for Void {
+    #[expect( // never dereferenced, as cannot be created
+        clippy::uninhabited_references,
+        reason = "`Void` is uninhabited"
+    )]
+    fn same_variant(&self, _: &Self) -> bool {
+        match *self {}
+    }
+}
+
+/// Trait for asserting associated types of delegates to be the same.
+#[diagnostic::on_unimplemented(
+    message = "delegate has `{Self}` associated type, while the first one has \
+               `{T}`",
+    label = "delegate with different associated type",
+    note = "associated types must be the same for all the delegates, unless \
+            marked with `#[delegate(sum)]` attribute argument"
+)]
+pub trait SameType<T: ?Sized> {}
+
+impl<T: ?Sized> SameType<T> for T {}
+
+/// Type asserting the provided types to be the same, once it's well-formed.
+#[derive(Debug)]
+pub struct AssertSameType<T: SameType<U> + ?Sized, U: ?Sized>(
+    PhantomData<U>,
+    PhantomData<T>,
+);
+
+/// Checks whether the provided string slices are equal in `const` context.
+#[must_use]
+pub const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (mut lhs, mut rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    loop {
+        match (lhs, rhs) {
+            ([], []) => return true,
+            ([l, lhs_rest @ ..], [r, rhs_rest @ ..]) if *l == *r => {
+                lhs = lhs_rest;
+                rhs = rhs_rest;
+            }
+            _ => return false,
+        }
+    }
+}
+
 /// Trait for retrieving an actual type from a bind type.
 pub trait TypeOf {
     /// Actual type associated with the bind.